@@ -0,0 +1,23 @@
+use alloc::vec::Vec;
+
+use arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+use crate::NonEmptyVec;
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for NonEmptyVec<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let head = T::arbitrary(u)?;
+        let tail = Vec::<T>::arbitrary(u)?;
+        Ok(Self::from_head_tail(head, tail))
+    }
+
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+        let head = T::arbitrary(&mut u)?;
+        let tail = Vec::<T>::arbitrary_take_rest(u)?;
+        Ok(Self::from_head_tail(head, tail))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(T::size_hint(depth), (0, None))
+    }
+}
@@ -0,0 +1,265 @@
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+use core::slice::SliceIndex;
+
+use crate::NonEmptySlice;
+
+/// Fails to compile when instantiated with `N == 0`.
+///
+/// Because it is a post-monomorphization error, `cargo check`
+/// may not report it, but `cargo build` always does.
+struct AssertNonEmpty<const N: usize>;
+
+impl<const N: usize> AssertNonEmpty<N> {
+    const OK: () = assert!(N != 0, "array shouldn't be empty");
+}
+
+/// A non-empty array type, counterpart of `[T; N]`.
+///
+/// It dereferences to [`NonEmptySlice`], so `first`, `last` and
+/// `split_first` don't return `Option`.
+/// Creating a `NonEmptyArray<T, 0>` fails to compile.
+///
+/// ```
+/// # use oom::NonEmptyArray;
+/// let a = NonEmptyArray::new([1, 2, 3]);
+/// assert_eq!(a.first(), &1);
+/// assert_eq!(a.split_first(), (&1, &[2, 3][..]));
+/// assert_eq!(a.map(|x| x * 2).last(), &6);
+/// ```
+///
+/// ```compile_fail
+/// # use oom::NonEmptyArray;
+/// let a = NonEmptyArray::<u8, 0>::new([]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct NonEmptyArray<T, const N: usize> {
+    inner: [T; N],
+}
+
+const _BUILTIN_TRAITS: () = {
+    impl<T: Eq, const N: usize> Eq for NonEmptyArray<T, N> {}
+
+    impl<T: PartialEq, const N: usize> PartialEq for NonEmptyArray<T, N> {
+        fn eq(&self, other: &Self) -> bool {
+            self.as_slice().eq(other.as_slice())
+        }
+    }
+
+    impl<T: Ord, const N: usize> Ord for NonEmptyArray<T, N> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.as_slice().cmp(other.as_slice())
+        }
+    }
+
+    impl<T: PartialOrd, const N: usize> PartialOrd for NonEmptyArray<T, N> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.as_slice().partial_cmp(other.as_slice())
+        }
+    }
+
+    impl<T, const N: usize> AsRef<[T]> for NonEmptyArray<T, N> {
+        fn as_ref(&self) -> &[T] {
+            self.as_slice()
+        }
+    }
+
+    impl<T, const N: usize> AsMut<[T]> for NonEmptyArray<T, N> {
+        fn as_mut(&mut self) -> &mut [T] {
+            self.as_mut_slice()
+        }
+    }
+
+    impl<T, const N: usize> Borrow<[T]> for NonEmptyArray<T, N> {
+        fn borrow(&self) -> &[T] {
+            self.as_slice()
+        }
+    }
+
+    impl<T, const N: usize> BorrowMut<[T]> for NonEmptyArray<T, N> {
+        fn borrow_mut(&mut self) -> &mut [T] {
+            self.as_mut_slice()
+        }
+    }
+
+    impl<T, const N: usize> Borrow<NonEmptySlice<T>> for NonEmptyArray<T, N> {
+        fn borrow(&self) -> &NonEmptySlice<T> {
+            self.as_nonempty_slice()
+        }
+    }
+
+    impl<T, const N: usize> BorrowMut<NonEmptySlice<T>> for NonEmptyArray<T, N> {
+        fn borrow_mut(&mut self) -> &mut NonEmptySlice<T> {
+            self.as_nonempty_mut_slice()
+        }
+    }
+
+    impl<T: Hash, const N: usize> Hash for NonEmptyArray<T, N> {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.inner.hash(state)
+        }
+    }
+
+    impl<T: fmt::Debug, const N: usize> fmt::Debug for NonEmptyArray<T, N> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.inner.fmt(f)
+        }
+    }
+
+    impl<T, I: SliceIndex<[T]>, const N: usize> Index<I> for NonEmptyArray<T, N> {
+        type Output = I::Output;
+
+        fn index(&self, index: I) -> &Self::Output {
+            self.as_slice().index(index)
+        }
+    }
+
+    impl<T, I: SliceIndex<[T]>, const N: usize> IndexMut<I>
+        for NonEmptyArray<T, N>
+    {
+        fn index_mut(&mut self, index: I) -> &mut Self::Output {
+            self.as_mut_slice().index_mut(index)
+        }
+    }
+
+    impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U]>
+        for NonEmptyArray<T, N>
+    {
+        fn eq(&self, other: &[U]) -> bool {
+            self.as_slice() == other
+        }
+    }
+
+    impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]>
+        for NonEmptyArray<T, N>
+    {
+        fn eq(&self, other: &[U; N]) -> bool {
+            self.inner == *other
+        }
+    }
+
+    impl<T, const N: usize> Deref for NonEmptyArray<T, N> {
+        type Target = NonEmptySlice<T>;
+
+        fn deref(&self) -> &Self::Target {
+            self.as_nonempty_slice()
+        }
+    }
+
+    impl<T, const N: usize> DerefMut for NonEmptyArray<T, N> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            self.as_nonempty_mut_slice()
+        }
+    }
+
+    impl<T, const N: usize> From<[T; N]> for NonEmptyArray<T, N> {
+        fn from(array: [T; N]) -> Self {
+            Self::new(array)
+        }
+    }
+
+    impl<T, const N: usize> From<NonEmptyArray<T, N>> for [T; N] {
+        fn from(array: NonEmptyArray<T, N>) -> Self {
+            array.inner
+        }
+    }
+
+    impl<T, const N: usize> IntoIterator for NonEmptyArray<T, N> {
+        type Item = T;
+        type IntoIter = core::array::IntoIter<T, N>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            IntoIterator::into_iter(self.inner)
+        }
+    }
+
+    impl<'a, T, const N: usize> From<&'a [T; N]> for &'a NonEmptySlice<T> {
+        fn from(array: &'a [T; N]) -> Self {
+            NonEmptySlice::from_array(array)
+        }
+    }
+
+    impl<'a, T, const N: usize> From<&'a mut [T; N]> for &'a mut NonEmptySlice<T> {
+        fn from(array: &'a mut [T; N]) -> Self {
+            NonEmptySlice::from_mut_array(array)
+        }
+    }
+
+    #[cfg(feature = "vec")]
+    impl<T, const N: usize> From<[T; N]> for crate::NonEmptyVec<T> {
+        fn from(array: [T; N]) -> Self {
+            let () = AssertNonEmpty::<N>::OK;
+            unsafe { Self::from_vec_unchecked(alloc::vec::Vec::from(array)) }
+        }
+    }
+
+    #[cfg(feature = "vec")]
+    impl<T, const N: usize> From<NonEmptyArray<T, N>> for crate::NonEmptyVec<T> {
+        fn from(array: NonEmptyArray<T, N>) -> Self {
+            Self::from(array.inner)
+        }
+    }
+};
+
+impl<T> NonEmptySlice<T> {
+    /// Converts a `&[T; N]` into a `&NonEmptySlice`.
+    ///
+    /// Fails to compile if `N == 0`.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let s = NonEmptySlice::from_array(&[1, 2]);
+    /// assert_eq!(s.last(), &2);
+    /// ```
+    pub const fn from_array<const N: usize>(array: &[T; N]) -> &Self {
+        let () = AssertNonEmpty::<N>::OK;
+        unsafe { Self::from_slice_unchecked(array) }
+    }
+
+    /// Converts a `&mut [T; N]` into a `&mut NonEmptySlice`.
+    ///
+    /// Fails to compile if `N == 0`.
+    pub fn from_mut_array<const N: usize>(array: &mut [T; N]) -> &mut Self {
+        let () = AssertNonEmpty::<N>::OK;
+        unsafe { Self::from_mut_slice_unchecked(array) }
+    }
+}
+
+impl<T, const N: usize> NonEmptyArray<T, N> {
+    /// Converts a `[T; N]` into a `NonEmptyArray`.
+    ///
+    /// Fails to compile if `N == 0`.
+    pub const fn new(array: [T; N]) -> Self {
+        let () = AssertNonEmpty::<N>::OK;
+        Self { inner: array }
+    }
+
+    /// Converts `self` into the inner array.
+    pub fn into_array(self) -> [T; N] {
+        self.inner
+    }
+
+    /// Returns a non-empty slice from this array.
+    pub const fn as_nonempty_slice(&self) -> &NonEmptySlice<T> {
+        NonEmptySlice::from_array(&self.inner)
+    }
+
+    /// Returns a non-empty mutable slice from this array.
+    pub fn as_nonempty_mut_slice(&mut self) -> &mut NonEmptySlice<T> {
+        NonEmptySlice::from_mut_array(&mut self.inner)
+    }
+
+    /// Returns an array of the same size as `self`, with `f` applied
+    /// to each element in order.
+    pub fn map<U, F>(self, f: F) -> NonEmptyArray<U, N>
+    where
+        F: FnMut(T) -> U,
+    {
+        NonEmptyArray {
+            inner: self.inner.map(f),
+        }
+    }
+}
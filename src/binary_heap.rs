@@ -0,0 +1,220 @@
+use alloc::collections::{binary_heap, BinaryHeap};
+use core::convert::TryFrom;
+use core::fmt;
+use core::hint::unreachable_unchecked;
+use core::num::NonZeroUsize;
+
+use crate::{EmptyError, NonEmptyVec};
+
+/// A non-empty priority queue, counterpart of `BinaryHeap<T>`.
+///
+/// ```
+/// # use oom::NonEmptyBinaryHeap;
+/// let mut h = NonEmptyBinaryHeap::new(0);
+/// h.push(5);
+/// assert_eq!(h.peek(), &5);
+/// assert_eq!(h.pop(), Some(5));
+/// assert_eq!(h.pop(), None);
+/// assert_eq!(h.into_sorted_vec(), [0]);
+/// ```
+#[derive(Clone)]
+pub struct NonEmptyBinaryHeap<T> {
+    inner: BinaryHeap<T>,
+}
+
+const _BUILTIN_TRAITS: () = {
+    impl<T: fmt::Debug> fmt::Debug for NonEmptyBinaryHeap<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.inner.fmt(f)
+        }
+    }
+
+    impl<T> AsRef<BinaryHeap<T>> for NonEmptyBinaryHeap<T> {
+        fn as_ref(&self) -> &BinaryHeap<T> {
+            &self.inner
+        }
+    }
+
+    impl<T> TryFrom<BinaryHeap<T>> for NonEmptyBinaryHeap<T> {
+        type Error = EmptyError;
+
+        fn try_from(heap: BinaryHeap<T>) -> Result<Self, Self::Error> {
+            Self::from_heap_checked(heap).map_err(|_| EmptyError)
+        }
+    }
+
+    impl<T> From<NonEmptyBinaryHeap<T>> for BinaryHeap<T> {
+        fn from(heap: NonEmptyBinaryHeap<T>) -> Self {
+            heap.inner
+        }
+    }
+
+    impl<T: Ord> From<NonEmptyVec<T>> for NonEmptyBinaryHeap<T> {
+        fn from(vec: NonEmptyVec<T>) -> Self {
+            Self {
+                inner: BinaryHeap::from(vec.into_vec()),
+            }
+        }
+    }
+
+    impl<T> From<NonEmptyBinaryHeap<T>> for NonEmptyVec<T> {
+        fn from(heap: NonEmptyBinaryHeap<T>) -> Self {
+            heap.into_vec()
+        }
+    }
+
+    impl<T> IntoIterator for NonEmptyBinaryHeap<T> {
+        type Item = T;
+        type IntoIter = binary_heap::IntoIter<T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.inner.into_iter()
+        }
+    }
+
+    impl<'a, T> IntoIterator for &'a NonEmptyBinaryHeap<T> {
+        type Item = &'a T;
+        type IntoIter = binary_heap::Iter<'a, T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    #[cfg(feature = "iter")]
+    impl<T: Ord> crate::FromNonEmptyIterator<T> for NonEmptyBinaryHeap<T> {
+        fn from_nonempty_iter<I>(iter: I) -> Self
+        where
+            I: crate::NonEmptyIterator<Item = T>,
+        {
+            Self {
+                inner: iter.into_iter().collect(),
+            }
+        }
+    }
+
+    impl<T: Ord> Extend<T> for NonEmptyBinaryHeap<T> {
+        fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
+
+    impl<'a, T: Ord + Copy + 'a> Extend<&'a T> for NonEmptyBinaryHeap<T> {
+        fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
+};
+
+impl<T> NonEmptyBinaryHeap<T> {
+    /// Constructs a new `NonEmptyBinaryHeap` containing a single element.
+    pub fn new(first: T) -> Self
+    where
+        T: Ord,
+    {
+        let mut inner = BinaryHeap::new();
+        inner.push(first);
+        Self { inner }
+    }
+
+    /// Converts a `BinaryHeap<T>` into a `NonEmptyBinaryHeap`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if passed `BinaryHeap` is empty.
+    pub fn from_heap(heap: BinaryHeap<T>) -> Self {
+        match Self::from_heap_checked(heap) {
+            Ok(h) => h,
+            Err(_) => panic!("heap shouldn't be empty"),
+        }
+    }
+
+    /// Converts a `BinaryHeap<T>` into a `NonEmptyBinaryHeap`.
+    /// Returns passed `BinaryHeap` if it is empty.
+    pub fn from_heap_checked(
+        heap: BinaryHeap<T>,
+    ) -> Result<Self, BinaryHeap<T>> {
+        if heap.is_empty() {
+            return Err(heap);
+        }
+        Ok(Self { inner: heap })
+    }
+
+    /// Converts a `BinaryHeap<T>` into a `NonEmptyBinaryHeap` without
+    /// checking that it is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The passed `BinaryHeap` must not be empty.
+    pub unsafe fn from_heap_unchecked(heap: BinaryHeap<T>) -> Self {
+        debug_assert!(!heap.is_empty());
+        Self { inner: heap }
+    }
+
+    /// Returns a reference to the inner `BinaryHeap`.
+    pub fn as_heap(&self) -> &BinaryHeap<T> {
+        &self.inner
+    }
+
+    /// Converts `self` into the inner `BinaryHeap`.
+    pub fn into_heap(self) -> BinaryHeap<T> {
+        self.inner
+    }
+
+    /// Returns the number of elements in the heap.
+    pub fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.inner.len()) }
+    }
+
+    /// Always returns `false` because the heap is non-empty.
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the greatest element of the heap.
+    pub fn peek(&self) -> &T {
+        match self.inner.peek() {
+            Some(top) => top,
+            None => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Pushes an element onto the heap.
+    pub fn push(&mut self, item: T)
+    where
+        T: Ord,
+    {
+        self.inner.push(item);
+    }
+
+    /// Removes the greatest element and returns it,
+    /// or `None` if it is the only element left.
+    pub fn pop(&mut self) -> Option<T>
+    where
+        T: Ord,
+    {
+        if self.inner.len() == 1 {
+            return None;
+        }
+        self.inner.pop()
+    }
+
+    /// Returns an iterator over the elements of the heap,
+    /// in arbitrary order.
+    pub fn iter(&self) -> binary_heap::Iter<'_, T> {
+        self.inner.iter()
+    }
+
+    /// Converts `self` into a vector, in arbitrary order.
+    pub fn into_vec(self) -> NonEmptyVec<T> {
+        unsafe { NonEmptyVec::from_vec_unchecked(self.inner.into_vec()) }
+    }
+
+    /// Converts `self` into a vector, sorted in ascending order.
+    pub fn into_sorted_vec(self) -> NonEmptyVec<T>
+    where
+        T: Ord,
+    {
+        unsafe { NonEmptyVec::from_vec_unchecked(self.inner.into_sorted_vec()) }
+    }
+}
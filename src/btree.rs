@@ -0,0 +1,559 @@
+use alloc::collections::{btree_map, btree_set, BTreeMap, BTreeSet};
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hint::unreachable_unchecked;
+use core::num::NonZeroUsize;
+use core::ops::Index;
+
+use crate::{EmptyError, NonEmptyVec};
+
+/// A non-empty ordered map, counterpart of `BTreeMap<K, V>`.
+///
+/// ```
+/// # use oom::NonEmptyBTreeMap;
+/// let mut m = NonEmptyBTreeMap::new("b", 2);
+/// m.insert("a", 1);
+/// assert_eq!(m.first_key_value(), (&"a", &1));
+/// assert_eq!(m.remove("a"), Some(1));
+/// assert_eq!(m.remove("b"), None);
+/// assert_eq!(m.len().get(), 1);
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyBTreeMap<K, V> {
+    inner: BTreeMap<K, V>,
+}
+
+/// A non-empty ordered set, counterpart of `BTreeSet<T>`.
+///
+/// ```
+/// # use oom::NonEmptyBTreeSet;
+/// let mut s = NonEmptyBTreeSet::new(3);
+/// s.insert(1);
+/// assert_eq!((s.first(), s.last()), (&1, &3));
+/// assert_eq!(s.pop_first(), Some(1));
+/// assert_eq!(s.pop_first(), None);
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyBTreeSet<T> {
+    inner: BTreeSet<T>,
+}
+
+const _BUILTIN_TRAITS: () = {
+    impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for NonEmptyBTreeMap<K, V> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.inner.fmt(f)
+        }
+    }
+
+    impl<K, V> AsRef<BTreeMap<K, V>> for NonEmptyBTreeMap<K, V> {
+        fn as_ref(&self) -> &BTreeMap<K, V> {
+            &self.inner
+        }
+    }
+
+    impl<K, Q, V> Index<&Q> for NonEmptyBTreeMap<K, V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        type Output = V;
+
+        fn index(&self, key: &Q) -> &V {
+            &self.inner[key]
+        }
+    }
+
+    impl<K: Ord, V> TryFrom<BTreeMap<K, V>> for NonEmptyBTreeMap<K, V> {
+        type Error = EmptyError;
+
+        fn try_from(map: BTreeMap<K, V>) -> Result<Self, Self::Error> {
+            Self::from_map_checked(map).map_err(|_| EmptyError)
+        }
+    }
+
+    impl<K, V> From<NonEmptyBTreeMap<K, V>> for BTreeMap<K, V> {
+        fn from(map: NonEmptyBTreeMap<K, V>) -> Self {
+            map.inner
+        }
+    }
+
+    impl<K: Ord, V> From<NonEmptyVec<(K, V)>> for NonEmptyBTreeMap<K, V> {
+        fn from(vec: NonEmptyVec<(K, V)>) -> Self {
+            Self {
+                inner: vec.into_iter().collect(),
+            }
+        }
+    }
+
+    impl<K, V> From<NonEmptyBTreeMap<K, V>> for NonEmptyVec<(K, V)> {
+        fn from(map: NonEmptyBTreeMap<K, V>) -> Self {
+            unsafe { Self::from_vec_unchecked(map.inner.into_iter().collect()) }
+        }
+    }
+
+    impl<K, V> IntoIterator for NonEmptyBTreeMap<K, V> {
+        type Item = (K, V);
+        type IntoIter = btree_map::IntoIter<K, V>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.inner.into_iter()
+        }
+    }
+
+    impl<'a, K, V> IntoIterator for &'a NonEmptyBTreeMap<K, V> {
+        type Item = (&'a K, &'a V);
+        type IntoIter = btree_map::Iter<'a, K, V>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<'a, K, V> IntoIterator for &'a mut NonEmptyBTreeMap<K, V> {
+        type Item = (&'a K, &'a mut V);
+        type IntoIter = btree_map::IterMut<'a, K, V>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+
+    #[cfg(feature = "iter")]
+    impl<K: Ord, V> crate::FromNonEmptyIterator<(K, V)> for NonEmptyBTreeMap<K, V> {
+        fn from_nonempty_iter<I>(iter: I) -> Self
+        where
+            I: crate::NonEmptyIterator<Item = (K, V)>,
+        {
+            Self {
+                inner: iter.into_iter().collect(),
+            }
+        }
+    }
+
+    impl<K: Ord, V> Extend<(K, V)> for NonEmptyBTreeMap<K, V> {
+        fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
+
+    impl<'a, K: Ord + Copy, V: Copy> Extend<(&'a K, &'a V)>
+        for NonEmptyBTreeMap<K, V>
+    {
+        fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
+
+    impl<T: fmt::Debug> fmt::Debug for NonEmptyBTreeSet<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.inner.fmt(f)
+        }
+    }
+
+    impl<T> AsRef<BTreeSet<T>> for NonEmptyBTreeSet<T> {
+        fn as_ref(&self) -> &BTreeSet<T> {
+            &self.inner
+        }
+    }
+
+    impl<T: Ord> TryFrom<BTreeSet<T>> for NonEmptyBTreeSet<T> {
+        type Error = EmptyError;
+
+        fn try_from(set: BTreeSet<T>) -> Result<Self, Self::Error> {
+            Self::from_set_checked(set).map_err(|_| EmptyError)
+        }
+    }
+
+    impl<T> From<NonEmptyBTreeSet<T>> for BTreeSet<T> {
+        fn from(set: NonEmptyBTreeSet<T>) -> Self {
+            set.inner
+        }
+    }
+
+    impl<T: Ord> From<NonEmptyVec<T>> for NonEmptyBTreeSet<T> {
+        fn from(vec: NonEmptyVec<T>) -> Self {
+            Self {
+                inner: vec.into_iter().collect(),
+            }
+        }
+    }
+
+    impl<T> From<NonEmptyBTreeSet<T>> for NonEmptyVec<T> {
+        fn from(set: NonEmptyBTreeSet<T>) -> Self {
+            unsafe { Self::from_vec_unchecked(set.inner.into_iter().collect()) }
+        }
+    }
+
+    impl<T> IntoIterator for NonEmptyBTreeSet<T> {
+        type Item = T;
+        type IntoIter = btree_set::IntoIter<T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.inner.into_iter()
+        }
+    }
+
+    impl<'a, T> IntoIterator for &'a NonEmptyBTreeSet<T> {
+        type Item = &'a T;
+        type IntoIter = btree_set::Iter<'a, T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    #[cfg(feature = "iter")]
+    impl<T: Ord> crate::FromNonEmptyIterator<T> for NonEmptyBTreeSet<T> {
+        fn from_nonempty_iter<I>(iter: I) -> Self
+        where
+            I: crate::NonEmptyIterator<Item = T>,
+        {
+            Self {
+                inner: iter.into_iter().collect(),
+            }
+        }
+    }
+
+    impl<T: Ord> Extend<T> for NonEmptyBTreeSet<T> {
+        fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
+
+    impl<'a, T: Ord + Copy + 'a> Extend<&'a T> for NonEmptyBTreeSet<T> {
+        fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
+};
+
+impl<K, V> NonEmptyBTreeMap<K, V> {
+    /// Constructs a new `NonEmptyBTreeMap` containing a single entry.
+    pub fn new(key: K, value: V) -> Self
+    where
+        K: Ord,
+    {
+        let mut inner = BTreeMap::new();
+        inner.insert(key, value);
+        Self { inner }
+    }
+
+    /// Converts a `BTreeMap<K, V>` into a `NonEmptyBTreeMap`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if passed `BTreeMap` is empty.
+    pub fn from_map(map: BTreeMap<K, V>) -> Self {
+        match Self::from_map_checked(map) {
+            Ok(m) => m,
+            Err(_) => panic!("map shouldn't be empty"),
+        }
+    }
+
+    /// Converts a `BTreeMap<K, V>` into a `NonEmptyBTreeMap`.
+    /// Returns passed `BTreeMap` if it is empty.
+    pub fn from_map_checked(
+        map: BTreeMap<K, V>,
+    ) -> Result<Self, BTreeMap<K, V>> {
+        if map.is_empty() {
+            return Err(map);
+        }
+        Ok(Self { inner: map })
+    }
+
+    /// Converts a `BTreeMap<K, V>` into a `NonEmptyBTreeMap` without
+    /// checking that it is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The passed `BTreeMap` must not be empty.
+    pub unsafe fn from_map_unchecked(map: BTreeMap<K, V>) -> Self {
+        debug_assert!(!map.is_empty());
+        Self { inner: map }
+    }
+
+    /// Returns a reference to the inner `BTreeMap`.
+    pub fn as_map(&self) -> &BTreeMap<K, V> {
+        &self.inner
+    }
+
+    /// Converts `self` into the inner `BTreeMap`.
+    pub fn into_map(self) -> BTreeMap<K, V> {
+        self.inner
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.inner.len()) }
+    }
+
+    /// Always returns `false` because the map is non-empty.
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the entry with the minimum key.
+    pub fn first_key_value(&self) -> (&K, &V) {
+        match self.inner.iter().next() {
+            Some(kv) => kv,
+            None => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns the entry with the maximum key.
+    pub fn last_key_value(&self) -> (&K, &V) {
+        match self.inner.iter().next_back() {
+            Some(kv) => kv,
+            None => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Removes the entry with the minimum key and returns it,
+    /// or `None` if it is the only entry left.
+    pub fn pop_first(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        if self.inner.len() == 1 {
+            return None;
+        }
+        self.inner.pop_first()
+    }
+
+    /// Removes the entry with the maximum key and returns it,
+    /// or `None` if it is the only entry left.
+    pub fn pop_last(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        if self.inner.len() == 1 {
+            return None;
+        }
+        self.inner.pop_last()
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.inner.get(key)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.inner.get_mut(key)
+    }
+
+    /// Returns `true` if the map contains a value for the key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.inner.contains_key(key)
+    }
+
+    /// Inserts a key-value pair into the map, returning the old value
+    /// if the key was present.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: Ord,
+    {
+        self.inner.insert(key, value)
+    }
+
+    /// Removes a key from the map, returning its value,
+    /// or `None` if the key is absent or it is the only entry left.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        if self.inner.len() == 1 {
+            return None;
+        }
+        self.inner.remove(key)
+    }
+
+    /// Returns an iterator over the entries of the map, sorted by key.
+    pub fn iter(&self) -> btree_map::Iter<'_, K, V> {
+        self.inner.iter()
+    }
+
+    /// Returns a mutable iterator over the entries of the map,
+    /// sorted by key.
+    pub fn iter_mut(&mut self) -> btree_map::IterMut<'_, K, V> {
+        self.inner.iter_mut()
+    }
+
+    /// Returns an iterator over the keys of the map, in sorted order.
+    pub fn keys(&self) -> btree_map::Keys<'_, K, V> {
+        self.inner.keys()
+    }
+
+    /// Returns an iterator over the values of the map, in order by key.
+    pub fn values(&self) -> btree_map::Values<'_, K, V> {
+        self.inner.values()
+    }
+
+    /// Returns a mutable iterator over the values of the map,
+    /// in order by key.
+    pub fn values_mut(&mut self) -> btree_map::ValuesMut<'_, K, V> {
+        self.inner.values_mut()
+    }
+}
+
+impl<T> NonEmptyBTreeSet<T> {
+    /// Constructs a new `NonEmptyBTreeSet` containing a single element.
+    pub fn new(value: T) -> Self
+    where
+        T: Ord,
+    {
+        let mut inner = BTreeSet::new();
+        inner.insert(value);
+        Self { inner }
+    }
+
+    /// Converts a `BTreeSet<T>` into a `NonEmptyBTreeSet`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if passed `BTreeSet` is empty.
+    pub fn from_set(set: BTreeSet<T>) -> Self {
+        match Self::from_set_checked(set) {
+            Ok(s) => s,
+            Err(_) => panic!("set shouldn't be empty"),
+        }
+    }
+
+    /// Converts a `BTreeSet<T>` into a `NonEmptyBTreeSet`.
+    /// Returns passed `BTreeSet` if it is empty.
+    pub fn from_set_checked(set: BTreeSet<T>) -> Result<Self, BTreeSet<T>> {
+        if set.is_empty() {
+            return Err(set);
+        }
+        Ok(Self { inner: set })
+    }
+
+    /// Converts a `BTreeSet<T>` into a `NonEmptyBTreeSet` without
+    /// checking that it is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The passed `BTreeSet` must not be empty.
+    pub unsafe fn from_set_unchecked(set: BTreeSet<T>) -> Self {
+        debug_assert!(!set.is_empty());
+        Self { inner: set }
+    }
+
+    /// Returns a reference to the inner `BTreeSet`.
+    pub fn as_set(&self) -> &BTreeSet<T> {
+        &self.inner
+    }
+
+    /// Converts `self` into the inner `BTreeSet`.
+    pub fn into_set(self) -> BTreeSet<T> {
+        self.inner
+    }
+
+    /// Returns the number of elements in the set.
+    pub fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.inner.len()) }
+    }
+
+    /// Always returns `false` because the set is non-empty.
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the minimum element of the set.
+    pub fn first(&self) -> &T {
+        match self.inner.iter().next() {
+            Some(first) => first,
+            None => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns the maximum element of the set.
+    pub fn last(&self) -> &T {
+        match self.inner.iter().next_back() {
+            Some(last) => last,
+            None => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Removes the minimum element and returns it,
+    /// or `None` if it is the only element left.
+    pub fn pop_first(&mut self) -> Option<T>
+    where
+        T: Ord,
+    {
+        if self.inner.len() == 1 {
+            return None;
+        }
+        self.inner.pop_first()
+    }
+
+    /// Removes the maximum element and returns it,
+    /// or `None` if it is the only element left.
+    pub fn pop_last(&mut self) -> Option<T>
+    where
+        T: Ord,
+    {
+        if self.inner.len() == 1 {
+            return None;
+        }
+        self.inner.pop_last()
+    }
+
+    /// Returns `true` if the set contains the value.
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.inner.contains(value)
+    }
+
+    /// Returns a reference to the element in the set equal to the value.
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.inner.get(value)
+    }
+
+    /// Adds a value to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, value: T) -> bool
+    where
+        T: Ord,
+    {
+        self.inner.insert(value)
+    }
+
+    /// Removes a value from the set, returning whether it was removed.
+    /// Returns `false` if the value is absent or it is the only element left.
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        if self.inner.len() == 1 {
+            return false;
+        }
+        self.inner.remove(value)
+    }
+
+    /// Returns an iterator over the elements of the set, in sorted order.
+    pub fn iter(&self) -> btree_set::Iter<'_, T> {
+        self.inner.iter()
+    }
+}
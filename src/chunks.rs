@@ -0,0 +1,557 @@
+//! Iterators over non-empty subslices of a [`NonEmptySlice`].
+//!
+//! [`Chunks`], [`RChunks`], [`ChunksMut`], [`GroupBy`] and
+//! [`SplitInclusive`] always yield at least one subslice, so like
+//! `iter::Iter` they are not [`Iterator`]s themselves and are turned
+//! into one with [`IntoIterator::into_iter`].
+//! With the `iter` feature, they implement `NonEmptyIterator`.
+//!
+//! [`ChunksExact`] and [`Windows`] may yield nothing if the size is
+//! greater than the length of the slice, and [`SplitNonEmpty`] may yield
+//! nothing if all elements are separators, so they are plain iterators.
+
+use core::fmt;
+use core::iter::{self, FusedIterator};
+use core::num::NonZeroUsize;
+use core::slice;
+
+use crate::NonEmptySlice;
+
+type ToNonEmpty<'a, T> = fn(&'a [T]) -> &'a NonEmptySlice<T>;
+type ToNonEmptyMut<'a, T> = fn(&'a mut [T]) -> &'a mut NonEmptySlice<T>;
+
+fn to_nonempty<T>(chunk: &[T]) -> &NonEmptySlice<T> {
+    unsafe { NonEmptySlice::from_slice_unchecked(chunk) }
+}
+
+fn to_nonempty_mut<T>(chunk: &mut [T]) -> &mut NonEmptySlice<T> {
+    unsafe { NonEmptySlice::from_mut_slice_unchecked(chunk) }
+}
+
+/// Non-empty iterator over non-empty chunks of a slice, starting at
+/// the beginning.
+///
+/// This `struct` is created by [`NonEmptySlice::chunks`].
+#[derive(Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Chunks<'a, T> {
+    inner: slice::Chunks<'a, T>,
+}
+
+impl<'a, T> Clone for Chunks<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for Chunks<'a, T> {
+    type Item = &'a NonEmptySlice<T>;
+    type IntoIter = iter::Map<slice::Chunks<'a, T>, ToNonEmpty<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.map(to_nonempty as ToNonEmpty<'a, T>)
+    }
+}
+
+#[cfg(feature = "iter")]
+unsafe impl<'a, T> crate::NonEmptyIterator for Chunks<'a, T> {}
+
+/// Non-empty iterator over non-empty chunks of a slice, starting at
+/// the end.
+///
+/// This `struct` is created by [`NonEmptySlice::rchunks`].
+#[derive(Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct RChunks<'a, T> {
+    inner: slice::RChunks<'a, T>,
+}
+
+impl<'a, T> Clone for RChunks<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for RChunks<'a, T> {
+    type Item = &'a NonEmptySlice<T>;
+    type IntoIter = iter::Map<slice::RChunks<'a, T>, ToNonEmpty<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.map(to_nonempty as ToNonEmpty<'a, T>)
+    }
+}
+
+#[cfg(feature = "iter")]
+unsafe impl<'a, T> crate::NonEmptyIterator for RChunks<'a, T> {}
+
+/// Non-empty iterator over non-empty mutable chunks of a slice,
+/// starting at the beginning.
+///
+/// This `struct` is created by [`NonEmptySlice::chunks_mut`].
+#[derive(Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct ChunksMut<'a, T> {
+    inner: slice::ChunksMut<'a, T>,
+}
+
+impl<'a, T> IntoIterator for ChunksMut<'a, T> {
+    type Item = &'a mut NonEmptySlice<T>;
+    type IntoIter = iter::Map<slice::ChunksMut<'a, T>, ToNonEmptyMut<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.map(to_nonempty_mut as ToNonEmptyMut<'a, T>)
+    }
+}
+
+#[cfg(feature = "iter")]
+unsafe impl<'a, T> crate::NonEmptyIterator for ChunksMut<'a, T> {}
+
+/// Iterator over non-empty chunks of exactly `chunk_size` elements,
+/// starting at the beginning.
+///
+/// This `struct` is created by [`NonEmptySlice::chunks_exact`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunksExact<'a, T> {
+    inner: slice::ChunksExact<'a, T>,
+}
+
+impl<'a, T> ChunksExact<'a, T> {
+    /// Returns the remainder of the slice that is not yielded,
+    /// with less than `chunk_size` elements.
+    pub fn remainder(&self) -> &'a [T] {
+        self.inner.remainder()
+    }
+}
+
+impl<'a, T> Clone for ChunksExact<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for ChunksExact<'a, T> {
+    type Item = &'a NonEmptySlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(to_nonempty)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ChunksExact<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(to_nonempty)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ChunksExact<'a, T> {}
+
+impl<'a, T> FusedIterator for ChunksExact<'a, T> {}
+
+/// Iterator over overlapping non-empty windows of a slice.
+///
+/// This `struct` is created by [`NonEmptySlice::windows`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Windows<'a, T> {
+    inner: slice::Windows<'a, T>,
+}
+
+impl<'a, T> Clone for Windows<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = &'a NonEmptySlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(to_nonempty)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Windows<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(to_nonempty)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Windows<'a, T> {}
+
+impl<'a, T> FusedIterator for Windows<'a, T> {}
+
+/// Non-empty iterator over groups of consecutive elements of a slice,
+/// separated where `pred` returns `false`.
+///
+/// This `struct` is created by [`NonEmptySlice::group_by`].
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct GroupBy<'a, T, P> {
+    inner: slice::ChunkBy<'a, T, P>,
+}
+
+impl<'a, T: fmt::Debug, P> fmt::Debug for GroupBy<'a, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<'a, T, P> IntoIterator for GroupBy<'a, T, P>
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    type Item = &'a NonEmptySlice<T>;
+    type IntoIter = iter::Map<slice::ChunkBy<'a, T, P>, ToNonEmpty<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.map(to_nonempty as ToNonEmpty<'a, T>)
+    }
+}
+
+#[cfg(feature = "iter")]
+unsafe impl<'a, T, P> crate::NonEmptyIterator for GroupBy<'a, T, P> where
+    P: FnMut(&T, &T) -> bool
+{
+}
+
+/// Non-empty iterator over mutable groups of consecutive elements of
+/// a slice, separated where `pred` returns `false`.
+///
+/// This `struct` is created by [`NonEmptySlice::group_by_mut`].
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct GroupByMut<'a, T, P> {
+    inner: slice::ChunkByMut<'a, T, P>,
+}
+
+impl<'a, T: fmt::Debug, P> fmt::Debug for GroupByMut<'a, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<'a, T, P> IntoIterator for GroupByMut<'a, T, P>
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    type Item = &'a mut NonEmptySlice<T>;
+    type IntoIter =
+        iter::Map<slice::ChunkByMut<'a, T, P>, ToNonEmptyMut<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.map(to_nonempty_mut as ToNonEmptyMut<'a, T>)
+    }
+}
+
+#[cfg(feature = "iter")]
+unsafe impl<'a, T, P> crate::NonEmptyIterator for GroupByMut<'a, T, P> where
+    P: FnMut(&T, &T) -> bool
+{
+}
+
+/// Non-empty iterator over subslices of a slice, each terminated by
+/// an element that matches `pred`, except possibly the last one.
+///
+/// This `struct` is created by [`NonEmptySlice::split_inclusive`].
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct SplitInclusive<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    inner: slice::SplitInclusive<'a, T, P>,
+}
+
+impl<'a, T: fmt::Debug, P> fmt::Debug for SplitInclusive<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<'a, T, P> IntoIterator for SplitInclusive<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = &'a NonEmptySlice<T>;
+    type IntoIter =
+        iter::Map<slice::SplitInclusive<'a, T, P>, ToNonEmpty<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.map(to_nonempty as ToNonEmpty<'a, T>)
+    }
+}
+
+#[cfg(feature = "iter")]
+unsafe impl<'a, T, P> crate::NonEmptyIterator for SplitInclusive<'a, T, P> where
+    P: FnMut(&T) -> bool
+{
+}
+
+/// Iterator over the non-empty subslices of a slice separated by
+/// elements that match `pred`.
+///
+/// This `struct` is created by [`NonEmptySlice::split_nonempty`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitNonEmpty<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    inner: slice::Split<'a, T, P>,
+}
+
+impl<'a, T: fmt::Debug, P> fmt::Debug for SplitNonEmpty<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<'a, T, P> Iterator for SplitNonEmpty<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = &'a NonEmptySlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.find_map(NonEmptySlice::from_slice_checked)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<'a, T, P> DoubleEndedIterator for SplitNonEmpty<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner
+            .by_ref()
+            .rev()
+            .find_map(NonEmptySlice::from_slice_checked)
+    }
+}
+
+impl<'a, T, P> FusedIterator for SplitNonEmpty<'a, T, P> where
+    P: FnMut(&T) -> bool
+{
+}
+
+/// Iterator over the non-empty mutable subslices of a slice separated
+/// by elements that match `pred`.
+///
+/// This `struct` is created by [`NonEmptySlice::split_nonempty_mut`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitNonEmptyMut<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    inner: slice::SplitMut<'a, T, P>,
+}
+
+impl<'a, T: fmt::Debug, P> fmt::Debug for SplitNonEmptyMut<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<'a, T, P> Iterator for SplitNonEmptyMut<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = &'a mut NonEmptySlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.find_map(NonEmptySlice::from_mut_slice_checked)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<'a, T, P> DoubleEndedIterator for SplitNonEmptyMut<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner
+            .by_ref()
+            .rev()
+            .find_map(NonEmptySlice::from_mut_slice_checked)
+    }
+}
+
+impl<'a, T, P> FusedIterator for SplitNonEmptyMut<'a, T, P> where
+    P: FnMut(&T) -> bool
+{
+}
+
+impl<T> NonEmptySlice<T> {
+    /// Returns a non-empty iterator over `chunk_size` elements of
+    /// the slice at a time, starting at the beginning.
+    ///
+    /// The last chunk may be shorter than `chunk_size`.
+    ///
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// # use oom::NonEmptySlice;
+    /// let s = NonEmptySlice::from_slice(&[1, 2, 3, 4, 5]);
+    /// let size = NonZeroUsize::new(2).unwrap();
+    /// let firsts: Vec<_> = s.chunks(size).into_iter().map(|c| c.first()).collect();
+    /// assert_eq!(firsts, [&1, &3, &5]);
+    /// ```
+    pub fn chunks(&self, chunk_size: NonZeroUsize) -> Chunks<'_, T> {
+        Chunks {
+            inner: self.as_slice().chunks(chunk_size.get()),
+        }
+    }
+
+    /// Returns a non-empty iterator over `chunk_size` elements of
+    /// the slice at a time, starting at the end.
+    ///
+    /// The last chunk may be shorter than `chunk_size`.
+    pub fn rchunks(&self, chunk_size: NonZeroUsize) -> RChunks<'_, T> {
+        RChunks {
+            inner: self.as_slice().rchunks(chunk_size.get()),
+        }
+    }
+
+    /// Returns a non-empty iterator over `chunk_size` elements of
+    /// the mutable slice at a time, starting at the beginning.
+    ///
+    /// The last chunk may be shorter than `chunk_size`.
+    pub fn chunks_mut(&mut self, chunk_size: NonZeroUsize) -> ChunksMut<'_, T> {
+        ChunksMut {
+            inner: self.as_mut_slice().chunks_mut(chunk_size.get()),
+        }
+    }
+
+    /// Returns an iterator over exactly `chunk_size` elements of
+    /// the slice at a time, starting at the beginning.
+    ///
+    /// The remaining elements can be retrieved with
+    /// [`ChunksExact::remainder`]. It yields nothing if `chunk_size`
+    /// is greater than the length of the slice.
+    pub fn chunks_exact(&self, chunk_size: NonZeroUsize) -> ChunksExact<'_, T> {
+        ChunksExact {
+            inner: self.as_slice().chunks_exact(chunk_size.get()),
+        }
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size`.
+    ///
+    /// It yields nothing if `size` is greater than the length of the slice.
+    ///
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// # use oom::NonEmptySlice;
+    /// let s = NonEmptySlice::from_slice(&[1, 2, 3]);
+    /// let size = NonZeroUsize::new(2).unwrap();
+    /// let lasts: Vec<_> = s.windows(size).map(|w| w.last()).collect();
+    /// assert_eq!(lasts, [&2, &3]);
+    /// ```
+    pub fn windows(&self, size: NonZeroUsize) -> Windows<'_, T> {
+        Windows {
+            inner: self.as_slice().windows(size.get()),
+        }
+    }
+
+    /// Returns a non-empty iterator over groups of consecutive elements,
+    /// where `pred` is called on each pair of adjacent elements and
+    /// a new group starts where it returns `false`.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let s = NonEmptySlice::from_slice(&[1, 1, 2, 3, 3, 3]);
+    /// let lens: Vec<_> = s.group_by(|a, b| a == b).into_iter().map(|g| g.len().get()).collect();
+    /// assert_eq!(lens, [2, 1, 3]);
+    /// ```
+    pub fn group_by<P>(&self, pred: P) -> GroupBy<'_, T, P>
+    where
+        P: FnMut(&T, &T) -> bool,
+    {
+        GroupBy {
+            inner: self.as_slice().chunk_by(pred),
+        }
+    }
+
+    /// Returns a non-empty iterator over mutable groups of consecutive
+    /// elements, where `pred` is called on each pair of adjacent elements
+    /// and a new group starts where it returns `false`.
+    pub fn group_by_mut<P>(&mut self, pred: P) -> GroupByMut<'_, T, P>
+    where
+        P: FnMut(&T, &T) -> bool,
+    {
+        GroupByMut {
+            inner: self.as_mut_slice().chunk_by_mut(pred),
+        }
+    }
+
+    /// Returns a non-empty iterator over subslices separated by elements
+    /// that match `pred`. The matched element is contained in the end of
+    /// the previous subslice, so no subslice is empty.
+    pub fn split_inclusive<P>(&self, pred: P) -> SplitInclusive<'_, T, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        SplitInclusive {
+            inner: self.as_slice().split_inclusive(pred),
+        }
+    }
+
+    /// Returns an iterator over subslices separated by elements that
+    /// match `pred`, skipping the empty ones.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let s = NonEmptySlice::from_slice(b",foo,,bar,");
+    /// let words: Vec<_> = s.split_nonempty(|&b| b == b',').map(|w| w.as_slice()).collect();
+    /// assert_eq!(words, [b"foo", b"bar"]);
+    /// ```
+    pub fn split_nonempty<P>(&self, pred: P) -> SplitNonEmpty<'_, T, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        SplitNonEmpty {
+            inner: self.as_slice().split(pred),
+        }
+    }
+
+    /// Returns an iterator over mutable subslices separated by elements
+    /// that match `pred`, skipping the empty ones.
+    pub fn split_nonempty_mut<P>(
+        &mut self,
+        pred: P,
+    ) -> SplitNonEmptyMut<'_, T, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        SplitNonEmptyMut {
+            inner: self.as_mut_slice().split_mut(pred),
+        }
+    }
+}
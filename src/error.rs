@@ -0,0 +1,175 @@
+#[cfg(feature = "string")]
+use alloc::string::String;
+#[cfg(feature = "vec")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "vec")]
+use core::num::NonZeroUsize;
+
+/// The error type returned when converting an empty collection
+/// into a non-empty one.
+///
+/// ```
+/// # #[cfg(feature = "slice")]
+/// # fn main() {
+/// use std::convert::TryFrom;
+/// use oom::{EmptyError, NonEmptySlice};
+///
+/// fn first_word(words: &[&str]) -> Result<usize, EmptyError> {
+///     let words = <&NonEmptySlice<_>>::try_from(words)?;
+///     Ok(words.first().len())
+/// }
+///
+/// assert_eq!(first_word(&["foo", "bar"]), Ok(3));
+/// assert!(first_word(&[]).is_err());
+/// # }
+/// # #[cfg(not(feature = "slice"))]
+/// # fn main() {}
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct EmptyError;
+
+impl fmt::Display for EmptyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("collection is empty")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EmptyError {}
+
+/// The error type returned when converting an empty `Vec<T>`
+/// into a `NonEmptyVec`.
+///
+/// The original `Vec` and its allocation can be recovered with
+/// [`into_vec`](EmptyVecError::into_vec).
+#[cfg(feature = "vec")]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct EmptyVecError<T> {
+    vec: Vec<T>,
+}
+
+#[cfg(feature = "vec")]
+impl<T> EmptyVecError<T> {
+    pub(crate) fn new(vec: Vec<T>) -> Self {
+        Self { vec }
+    }
+
+    /// Returns the empty `Vec` that was attempted to convert.
+    pub fn into_vec(self) -> Vec<T> {
+        self.vec
+    }
+}
+
+#[cfg(feature = "vec")]
+impl<T> fmt::Debug for EmptyVecError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EmptyVecError")
+            .field("capacity", &self.vec.capacity())
+            .finish()
+    }
+}
+
+#[cfg(feature = "vec")]
+impl<T> fmt::Display for EmptyVecError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("vec is empty")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for EmptyVecError<T> {}
+
+#[cfg(feature = "vec")]
+impl<T> From<EmptyVecError<T>> for EmptyError {
+    fn from(_: EmptyVecError<T>) -> Self {
+        EmptyError
+    }
+}
+
+/// The error type returned when converting an empty `String`
+/// into a `NonEmptyString`.
+///
+/// The original `String` and its allocation can be recovered with
+/// [`into_string`](EmptyStringError::into_string).
+#[cfg(feature = "string")]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct EmptyStringError {
+    string: String,
+}
+
+#[cfg(feature = "string")]
+impl EmptyStringError {
+    pub(crate) fn new(string: String) -> Self {
+        Self { string }
+    }
+
+    /// Returns the empty `String` that was attempted to convert.
+    pub fn into_string(self) -> String {
+        self.string
+    }
+}
+
+#[cfg(feature = "string")]
+impl fmt::Debug for EmptyStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EmptyStringError")
+            .field("capacity", &self.string.capacity())
+            .finish()
+    }
+}
+
+#[cfg(feature = "string")]
+impl fmt::Display for EmptyStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("string is empty")
+    }
+}
+
+#[cfg(all(feature = "std", feature = "string"))]
+impl std::error::Error for EmptyStringError {}
+
+#[cfg(feature = "string")]
+impl From<EmptyStringError> for EmptyError {
+    fn from(_: EmptyStringError) -> Self {
+        EmptyError
+    }
+}
+
+/// The error type returned by [`NonEmptyVec::swap_remove`] when no element
+/// was removed.
+///
+/// [`NonEmptyVec::swap_remove`]: crate::NonEmptyVec::swap_remove
+#[cfg(feature = "vec")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RemoveError {
+    /// The index is out of bounds.
+    OutOfBounds {
+        /// The requested index.
+        index: usize,
+        /// The length of the collection.
+        len: NonZeroUsize,
+    },
+    /// The element is the only one left, and removing it would leave
+    /// the collection empty.
+    LastElement,
+}
+
+#[cfg(feature = "vec")]
+impl fmt::Display for RemoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds { index, len } => write!(
+                f,
+                "removal index (is {}) should be < len (is {})",
+                index, len
+            ),
+            Self::LastElement => f.write_str("cannot remove the last element"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RemoveError {}
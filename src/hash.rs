@@ -0,0 +1,554 @@
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::num::NonZeroUsize;
+use core::ops::Index;
+use std::collections::hash_map::{self, HashMap, RandomState};
+use std::collections::hash_set::{self, HashSet};
+
+use crate::{EmptyError, NonEmptyVec};
+
+/// A non-empty hash map, counterpart of `HashMap<K, V, S>`.
+///
+/// ```
+/// # use oom::NonEmptyHashMap;
+/// let mut m = NonEmptyHashMap::new("a", 1);
+/// m.insert("b", 2);
+/// assert_eq!(m.len().get(), 2);
+/// assert_eq!(m.remove("a"), Some(1));
+/// assert_eq!(m.remove("b"), None);
+/// assert_eq!(m["b"], 2);
+/// ```
+#[derive(Clone)]
+pub struct NonEmptyHashMap<K, V, S = RandomState> {
+    inner: HashMap<K, V, S>,
+}
+
+/// A non-empty hash set, counterpart of `HashSet<T, S>`.
+///
+/// ```
+/// # use oom::NonEmptyHashSet;
+/// let mut s = NonEmptyHashSet::new("read");
+/// assert!(s.insert("write"));
+/// assert!(s.remove("write"));
+/// assert!(!s.remove("read"));
+/// assert!(s.contains("read"));
+/// ```
+#[derive(Clone)]
+pub struct NonEmptyHashSet<T, S = RandomState> {
+    inner: HashSet<T, S>,
+}
+
+const _BUILTIN_TRAITS: () = {
+    impl<K, V, S> Eq for NonEmptyHashMap<K, V, S>
+    where
+        K: Eq + Hash,
+        V: Eq,
+        S: BuildHasher,
+    {
+    }
+
+    impl<K, V, S> PartialEq for NonEmptyHashMap<K, V, S>
+    where
+        K: Eq + Hash,
+        V: PartialEq,
+        S: BuildHasher,
+    {
+        fn eq(&self, other: &Self) -> bool {
+            self.inner == other.inner
+        }
+    }
+
+    impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for NonEmptyHashMap<K, V, S> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.inner.fmt(f)
+        }
+    }
+
+    impl<K, V, S> AsRef<HashMap<K, V, S>> for NonEmptyHashMap<K, V, S> {
+        fn as_ref(&self) -> &HashMap<K, V, S> {
+            &self.inner
+        }
+    }
+
+    impl<K, Q, V, S> Index<&Q> for NonEmptyHashMap<K, V, S>
+    where
+        K: Eq + Hash + Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+        S: BuildHasher,
+    {
+        type Output = V;
+
+        fn index(&self, key: &Q) -> &V {
+            &self.inner[key]
+        }
+    }
+
+    impl<K, V, S> TryFrom<HashMap<K, V, S>> for NonEmptyHashMap<K, V, S> {
+        type Error = EmptyError;
+
+        fn try_from(map: HashMap<K, V, S>) -> Result<Self, Self::Error> {
+            Self::from_map_checked(map).map_err(|_| EmptyError)
+        }
+    }
+
+    impl<K, V, S> From<NonEmptyHashMap<K, V, S>> for HashMap<K, V, S> {
+        fn from(map: NonEmptyHashMap<K, V, S>) -> Self {
+            map.inner
+        }
+    }
+
+    impl<K, V, S> From<NonEmptyVec<(K, V)>> for NonEmptyHashMap<K, V, S>
+    where
+        K: Eq + Hash,
+        S: BuildHasher + Default,
+    {
+        fn from(vec: NonEmptyVec<(K, V)>) -> Self {
+            Self {
+                inner: vec.into_iter().collect(),
+            }
+        }
+    }
+
+    impl<K, V, S> From<NonEmptyHashMap<K, V, S>> for NonEmptyVec<(K, V)> {
+        fn from(map: NonEmptyHashMap<K, V, S>) -> Self {
+            unsafe { Self::from_vec_unchecked(map.inner.into_iter().collect()) }
+        }
+    }
+
+    impl<K, V, S> IntoIterator for NonEmptyHashMap<K, V, S> {
+        type Item = (K, V);
+        type IntoIter = hash_map::IntoIter<K, V>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.inner.into_iter()
+        }
+    }
+
+    impl<'a, K, V, S> IntoIterator for &'a NonEmptyHashMap<K, V, S> {
+        type Item = (&'a K, &'a V);
+        type IntoIter = hash_map::Iter<'a, K, V>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<'a, K, V, S> IntoIterator for &'a mut NonEmptyHashMap<K, V, S> {
+        type Item = (&'a K, &'a mut V);
+        type IntoIter = hash_map::IterMut<'a, K, V>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+
+    #[cfg(feature = "iter")]
+    impl<K, V, S> crate::FromNonEmptyIterator<(K, V)> for NonEmptyHashMap<K, V, S>
+    where
+        K: Eq + Hash,
+        S: BuildHasher + Default,
+    {
+        fn from_nonempty_iter<I>(iter: I) -> Self
+        where
+            I: crate::NonEmptyIterator<Item = (K, V)>,
+        {
+            Self {
+                inner: iter.into_iter().collect(),
+            }
+        }
+    }
+
+    impl<K, V, S> Extend<(K, V)> for NonEmptyHashMap<K, V, S>
+    where
+        K: Eq + Hash,
+        S: BuildHasher,
+    {
+        fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
+
+    impl<T, S> Eq for NonEmptyHashSet<T, S>
+    where
+        T: Eq + Hash,
+        S: BuildHasher,
+    {
+    }
+
+    impl<T, S> PartialEq for NonEmptyHashSet<T, S>
+    where
+        T: Eq + Hash,
+        S: BuildHasher,
+    {
+        fn eq(&self, other: &Self) -> bool {
+            self.inner == other.inner
+        }
+    }
+
+    impl<T: fmt::Debug, S> fmt::Debug for NonEmptyHashSet<T, S> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.inner.fmt(f)
+        }
+    }
+
+    impl<T, S> AsRef<HashSet<T, S>> for NonEmptyHashSet<T, S> {
+        fn as_ref(&self) -> &HashSet<T, S> {
+            &self.inner
+        }
+    }
+
+    impl<T, S> TryFrom<HashSet<T, S>> for NonEmptyHashSet<T, S> {
+        type Error = EmptyError;
+
+        fn try_from(set: HashSet<T, S>) -> Result<Self, Self::Error> {
+            Self::from_set_checked(set).map_err(|_| EmptyError)
+        }
+    }
+
+    impl<T, S> From<NonEmptyHashSet<T, S>> for HashSet<T, S> {
+        fn from(set: NonEmptyHashSet<T, S>) -> Self {
+            set.inner
+        }
+    }
+
+    impl<T, S> From<NonEmptyVec<T>> for NonEmptyHashSet<T, S>
+    where
+        T: Eq + Hash,
+        S: BuildHasher + Default,
+    {
+        fn from(vec: NonEmptyVec<T>) -> Self {
+            Self {
+                inner: vec.into_iter().collect(),
+            }
+        }
+    }
+
+    impl<T, S> From<NonEmptyHashSet<T, S>> for NonEmptyVec<T> {
+        fn from(set: NonEmptyHashSet<T, S>) -> Self {
+            unsafe { Self::from_vec_unchecked(set.inner.into_iter().collect()) }
+        }
+    }
+
+    impl<T, S> IntoIterator for NonEmptyHashSet<T, S> {
+        type Item = T;
+        type IntoIter = hash_set::IntoIter<T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.inner.into_iter()
+        }
+    }
+
+    impl<'a, T, S> IntoIterator for &'a NonEmptyHashSet<T, S> {
+        type Item = &'a T;
+        type IntoIter = hash_set::Iter<'a, T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    #[cfg(feature = "iter")]
+    impl<T, S> crate::FromNonEmptyIterator<T> for NonEmptyHashSet<T, S>
+    where
+        T: Eq + Hash,
+        S: BuildHasher + Default,
+    {
+        fn from_nonempty_iter<I>(iter: I) -> Self
+        where
+            I: crate::NonEmptyIterator<Item = T>,
+        {
+            Self {
+                inner: iter.into_iter().collect(),
+            }
+        }
+    }
+
+    impl<T, S> Extend<T> for NonEmptyHashSet<T, S>
+    where
+        T: Eq + Hash,
+        S: BuildHasher,
+    {
+        fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
+};
+
+impl<K: Eq + Hash, V> NonEmptyHashMap<K, V> {
+    /// Constructs a new `NonEmptyHashMap` containing a single entry.
+    pub fn new(key: K, value: V) -> Self {
+        Self::with_hasher(key, value, RandomState::new())
+    }
+}
+
+impl<K, V, S> NonEmptyHashMap<K, V, S> {
+    /// Constructs a new `NonEmptyHashMap` containing a single entry,
+    /// which will use the given hash builder to hash keys.
+    pub fn with_hasher(key: K, value: V, hasher: S) -> Self
+    where
+        K: Eq + Hash,
+        S: BuildHasher,
+    {
+        let mut inner = HashMap::with_hasher(hasher);
+        inner.insert(key, value);
+        Self { inner }
+    }
+
+    /// Converts a `HashMap<K, V, S>` into a `NonEmptyHashMap`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if passed `HashMap` is empty.
+    pub fn from_map(map: HashMap<K, V, S>) -> Self {
+        match Self::from_map_checked(map) {
+            Ok(m) => m,
+            Err(_) => panic!("map shouldn't be empty"),
+        }
+    }
+
+    /// Converts a `HashMap<K, V, S>` into a `NonEmptyHashMap`.
+    /// Returns passed `HashMap` if it is empty.
+    pub fn from_map_checked(
+        map: HashMap<K, V, S>,
+    ) -> Result<Self, HashMap<K, V, S>> {
+        if map.is_empty() {
+            return Err(map);
+        }
+        Ok(Self { inner: map })
+    }
+
+    /// Converts a `HashMap<K, V, S>` into a `NonEmptyHashMap` without
+    /// checking that it is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The passed `HashMap` must not be empty.
+    pub unsafe fn from_map_unchecked(map: HashMap<K, V, S>) -> Self {
+        debug_assert!(!map.is_empty());
+        Self { inner: map }
+    }
+
+    /// Returns a reference to the inner `HashMap`.
+    pub fn as_map(&self) -> &HashMap<K, V, S> {
+        &self.inner
+    }
+
+    /// Converts `self` into the inner `HashMap`.
+    pub fn into_map(self) -> HashMap<K, V, S> {
+        self.inner
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.inner.len()) }
+    }
+
+    /// Always returns `false` because the map is non-empty.
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Eq + Hash + Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+        S: BuildHasher,
+    {
+        self.inner.get(key)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Eq + Hash + Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+        S: BuildHasher,
+    {
+        self.inner.get_mut(key)
+    }
+
+    /// Returns `true` if the map contains a value for the key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Eq + Hash + Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+        S: BuildHasher,
+    {
+        self.inner.contains_key(key)
+    }
+
+    /// Inserts a key-value pair into the map, returning the old value
+    /// if the key was present.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: Eq + Hash,
+        S: BuildHasher,
+    {
+        self.inner.insert(key, value)
+    }
+
+    /// Removes a key from the map, returning its value,
+    /// or `None` if the key is absent or it is the only entry left.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Eq + Hash + Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+        S: BuildHasher,
+    {
+        if self.inner.len() == 1 {
+            return None;
+        }
+        self.inner.remove(key)
+    }
+
+    /// Returns an iterator over the entries of the map, in arbitrary order.
+    pub fn iter(&self) -> hash_map::Iter<'_, K, V> {
+        self.inner.iter()
+    }
+
+    /// Returns a mutable iterator over the entries of the map,
+    /// in arbitrary order.
+    pub fn iter_mut(&mut self) -> hash_map::IterMut<'_, K, V> {
+        self.inner.iter_mut()
+    }
+
+    /// Returns an iterator over the keys of the map, in arbitrary order.
+    pub fn keys(&self) -> hash_map::Keys<'_, K, V> {
+        self.inner.keys()
+    }
+
+    /// Returns an iterator over the values of the map, in arbitrary order.
+    pub fn values(&self) -> hash_map::Values<'_, K, V> {
+        self.inner.values()
+    }
+
+    /// Returns a mutable iterator over the values of the map,
+    /// in arbitrary order.
+    pub fn values_mut(&mut self) -> hash_map::ValuesMut<'_, K, V> {
+        self.inner.values_mut()
+    }
+}
+
+impl<T: Eq + Hash> NonEmptyHashSet<T> {
+    /// Constructs a new `NonEmptyHashSet` containing a single element.
+    pub fn new(value: T) -> Self {
+        Self::with_hasher(value, RandomState::new())
+    }
+}
+
+impl<T, S> NonEmptyHashSet<T, S> {
+    /// Constructs a new `NonEmptyHashSet` containing a single element,
+    /// which will use the given hash builder to hash values.
+    pub fn with_hasher(value: T, hasher: S) -> Self
+    where
+        T: Eq + Hash,
+        S: BuildHasher,
+    {
+        let mut inner = HashSet::with_hasher(hasher);
+        inner.insert(value);
+        Self { inner }
+    }
+
+    /// Converts a `HashSet<T, S>` into a `NonEmptyHashSet`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if passed `HashSet` is empty.
+    pub fn from_set(set: HashSet<T, S>) -> Self {
+        match Self::from_set_checked(set) {
+            Ok(s) => s,
+            Err(_) => panic!("set shouldn't be empty"),
+        }
+    }
+
+    /// Converts a `HashSet<T, S>` into a `NonEmptyHashSet`.
+    /// Returns passed `HashSet` if it is empty.
+    pub fn from_set_checked(set: HashSet<T, S>) -> Result<Self, HashSet<T, S>> {
+        if set.is_empty() {
+            return Err(set);
+        }
+        Ok(Self { inner: set })
+    }
+
+    /// Converts a `HashSet<T, S>` into a `NonEmptyHashSet` without
+    /// checking that it is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The passed `HashSet` must not be empty.
+    pub unsafe fn from_set_unchecked(set: HashSet<T, S>) -> Self {
+        debug_assert!(!set.is_empty());
+        Self { inner: set }
+    }
+
+    /// Returns a reference to the inner `HashSet`.
+    pub fn as_set(&self) -> &HashSet<T, S> {
+        &self.inner
+    }
+
+    /// Converts `self` into the inner `HashSet`.
+    pub fn into_set(self) -> HashSet<T, S> {
+        self.inner
+    }
+
+    /// Returns the number of elements in the set.
+    pub fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.inner.len()) }
+    }
+
+    /// Always returns `false` because the set is non-empty.
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if the set contains the value.
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Eq + Hash + Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+        S: BuildHasher,
+    {
+        self.inner.contains(value)
+    }
+
+    /// Returns a reference to the element in the set equal to the value.
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Eq + Hash + Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+        S: BuildHasher,
+    {
+        self.inner.get(value)
+    }
+
+    /// Adds a value to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, value: T) -> bool
+    where
+        T: Eq + Hash,
+        S: BuildHasher,
+    {
+        self.inner.insert(value)
+    }
+
+    /// Removes a value from the set, returning whether it was removed.
+    /// Returns `false` if the value is absent or it is the only element left.
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Eq + Hash + Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+        S: BuildHasher,
+    {
+        if self.inner.len() == 1 {
+            return false;
+        }
+        self.inner.remove(value)
+    }
+
+    /// Returns an iterator over the elements of the set, in arbitrary order.
+    pub fn iter(&self) -> hash_set::Iter<'_, T> {
+        self.inner.iter()
+    }
+}
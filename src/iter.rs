@@ -0,0 +1,487 @@
+//! Iterators that are guaranteed to yield at least one item.
+//!
+//! A [`NonEmptyIterator`] is not an [`Iterator`] itself, because an
+//! `Iterator` could be advanced until it is exhausted.
+//! Instead, it can be turned into one with [`IntoIterator::into_iter`]
+//! after the non-empty guarantee is no longer needed.
+
+use core::hint::unreachable_unchecked;
+use core::iter;
+use core::num::NonZeroUsize;
+use core::slice;
+
+/// An iterator that yields at least one item.
+///
+/// Unlike [`Iterator`], reductions like [`max`] and [`reduce`]
+/// return an item directly instead of an `Option`.
+///
+/// ```
+/// use oom::{NonEmptyIterator, NonEmptySlice};
+///
+/// let s = NonEmptySlice::from_slice(&[3, 1, 4, 1, 5]);
+/// assert_eq!(s.nonempty_iter().max(), &5);
+/// assert_eq!(s.nonempty_iter().copied().reduce(|a, b| a + b), 14);
+/// ```
+///
+/// # Safety
+///
+/// Implementors must guarantee that the iterator returned by
+/// [`IntoIterator::into_iter`] yields at least one item.
+///
+/// [`max`]: NonEmptyIterator::max
+/// [`reduce`]: NonEmptyIterator::reduce
+pub unsafe trait NonEmptyIterator: IntoIterator + Sized {
+    /// Returns the first item of the iterator.
+    fn first(self) -> Self::Item {
+        unwrap(self.into_iter().next())
+    }
+
+    /// Returns the last item of the iterator.
+    fn last(self) -> Self::Item {
+        unwrap(self.into_iter().last())
+    }
+
+    /// Returns the number of items of the iterator.
+    fn count(self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.into_iter().count()) }
+    }
+
+    /// Returns the maximum item of the iterator.
+    ///
+    /// If several items are equally maximum, the last one is returned.
+    fn max(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        unwrap(self.into_iter().max())
+    }
+
+    /// Returns the minimum item of the iterator.
+    ///
+    /// If several items are equally minimum, the first one is returned.
+    fn min(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        unwrap(self.into_iter().min())
+    }
+
+    /// Returns the item that gives the maximum value from the
+    /// specified function.
+    fn max_by_key<B, F>(self, f: F) -> Self::Item
+    where
+        B: Ord,
+        F: FnMut(&Self::Item) -> B,
+    {
+        unwrap(self.into_iter().max_by_key(f))
+    }
+
+    /// Returns the item that gives the minimum value from the
+    /// specified function.
+    fn min_by_key<B, F>(self, f: F) -> Self::Item
+    where
+        B: Ord,
+        F: FnMut(&Self::Item) -> B,
+    {
+        unwrap(self.into_iter().min_by_key(f))
+    }
+
+    /// Returns the item that gives the maximum value with respect to the
+    /// specified comparison function.
+    fn max_by<F>(self, compare: F) -> Self::Item
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> core::cmp::Ordering,
+    {
+        unwrap(self.into_iter().max_by(compare))
+    }
+
+    /// Returns the item that gives the minimum value with respect to the
+    /// specified comparison function.
+    fn min_by<F>(self, compare: F) -> Self::Item
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> core::cmp::Ordering,
+    {
+        unwrap(self.into_iter().min_by(compare))
+    }
+
+    /// Reduces the items to a single one, by repeatedly applying
+    /// a reducing operation.
+    fn reduce<F>(self, f: F) -> Self::Item
+    where
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        let mut iter = self.into_iter();
+        let first = unwrap(iter.next());
+        iter.fold(first, f)
+    }
+
+    /// Transforms the iterator into a collection without checking
+    /// whether it is empty.
+    ///
+    /// ```
+    /// # #[cfg(feature = "vec")]
+    /// # fn main() {
+    /// use oom::{NonEmptyIterator, NonEmptyVec};
+    ///
+    /// let v = oom::nonempty![1, 2, 3];
+    /// let doubled: NonEmptyVec<_> = v.nonempty_iter().map(|x| x * 2).collect();
+    /// assert_eq!(doubled.as_slice(), &[2, 4, 6]);
+    /// # }
+    /// # #[cfg(not(feature = "vec"))]
+    /// # fn main() {}
+    /// ```
+    fn collect<B>(self) -> B
+    where
+        B: FromNonEmptyIterator<Self::Item>,
+    {
+        B::from_nonempty_iter(self)
+    }
+
+    /// Takes a closure and creates an iterator which calls that closure
+    /// on each item.
+    fn map<B, F>(self, f: F) -> Map<Self, F>
+    where
+        F: FnMut(Self::Item) -> B,
+    {
+        Map { iter: self, f }
+    }
+
+    /// Creates an iterator which gives the current iteration count
+    /// as well as the next value.
+    fn enumerate(self) -> Enumerate<Self> {
+        Enumerate { iter: self }
+    }
+
+    /// "Zips up" two non-empty iterators into a single non-empty iterator
+    /// of pairs.
+    fn zip<U>(self, other: U) -> Zip<Self, U>
+    where
+        U: NonEmptyIterator,
+    {
+        Zip { a: self, b: other }
+    }
+
+    /// Takes two iterators and creates a new iterator over both
+    /// in sequence.
+    ///
+    /// Only `self` needs to be non-empty.
+    fn chain<U>(self, other: U) -> Chain<Self, U>
+    where
+        U: IntoIterator<Item = Self::Item>,
+    {
+        Chain { a: self, b: other }
+    }
+
+    /// Creates an iterator which clones all of its items.
+    fn cloned<'a, T>(self) -> Cloned<Self>
+    where
+        T: 'a + Clone,
+        Self: NonEmptyIterator<Item = &'a T>,
+    {
+        Cloned { iter: self }
+    }
+
+    /// Creates an iterator which copies all of its items.
+    fn copied<'a, T>(self) -> Copied<Self>
+    where
+        T: 'a + Copy,
+        Self: NonEmptyIterator<Item = &'a T>,
+    {
+        Copied { iter: self }
+    }
+
+    /// Reverses an iterator's direction.
+    fn rev(self) -> Rev<Self>
+    where
+        Self::IntoIter: DoubleEndedIterator,
+    {
+        Rev { iter: self }
+    }
+}
+
+/// Conversion from a [`NonEmptyIterator`].
+pub trait FromNonEmptyIterator<A>: Sized {
+    /// Creates a value from a non-empty iterator.
+    fn from_nonempty_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = A>;
+}
+
+#[inline]
+fn unwrap<T>(item: Option<T>) -> T {
+    match item {
+        Some(item) => item,
+        None => unsafe { unreachable_unchecked() },
+    }
+}
+
+/// A non-empty iterator that maps the values of `iter` with `f`.
+///
+/// This `struct` is created by [`NonEmptyIterator::map`].
+#[derive(Clone, Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Map<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<B, I, F> IntoIterator for Map<I, F>
+where
+    I: NonEmptyIterator,
+    F: FnMut(I::Item) -> B,
+{
+    type Item = B;
+    type IntoIter = iter::Map<I::IntoIter, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.into_iter().map(self.f)
+    }
+}
+
+unsafe impl<B, I, F> NonEmptyIterator for Map<I, F>
+where
+    I: NonEmptyIterator,
+    F: FnMut(I::Item) -> B,
+{
+}
+
+/// A non-empty iterator that yields the current count and the item.
+///
+/// This `struct` is created by [`NonEmptyIterator::enumerate`].
+#[derive(Clone, Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Enumerate<I> {
+    iter: I,
+}
+
+impl<I: NonEmptyIterator> IntoIterator for Enumerate<I> {
+    type Item = (usize, I::Item);
+    type IntoIter = iter::Enumerate<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.into_iter().enumerate()
+    }
+}
+
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for Enumerate<I> {}
+
+/// A non-empty iterator that iterates two other non-empty iterators
+/// simultaneously.
+///
+/// This `struct` is created by [`NonEmptyIterator::zip`].
+#[derive(Clone, Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Zip<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> IntoIterator for Zip<A, B>
+where
+    A: NonEmptyIterator,
+    B: NonEmptyIterator,
+{
+    type Item = (A::Item, B::Item);
+    type IntoIter = iter::Zip<A::IntoIter, B::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.a.into_iter().zip(self.b)
+    }
+}
+
+unsafe impl<A, B> NonEmptyIterator for Zip<A, B>
+where
+    A: NonEmptyIterator,
+    B: NonEmptyIterator,
+{
+}
+
+/// A non-empty iterator that links two iterators together, in a chain.
+///
+/// This `struct` is created by [`NonEmptyIterator::chain`].
+#[derive(Clone, Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Chain<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> IntoIterator for Chain<A, B>
+where
+    A: NonEmptyIterator,
+    B: IntoIterator<Item = A::Item>,
+{
+    type Item = A::Item;
+    type IntoIter = iter::Chain<A::IntoIter, B::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.a.into_iter().chain(self.b)
+    }
+}
+
+unsafe impl<A, B> NonEmptyIterator for Chain<A, B>
+where
+    A: NonEmptyIterator,
+    B: IntoIterator<Item = A::Item>,
+{
+}
+
+/// A non-empty iterator that clones the items of an underlying iterator.
+///
+/// This `struct` is created by [`NonEmptyIterator::cloned`].
+#[derive(Clone, Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Cloned<I> {
+    iter: I,
+}
+
+impl<'a, I, T> IntoIterator for Cloned<I>
+where
+    T: 'a + Clone,
+    I: NonEmptyIterator<Item = &'a T>,
+{
+    type Item = T;
+    type IntoIter = iter::Cloned<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.into_iter().cloned()
+    }
+}
+
+unsafe impl<'a, I, T> NonEmptyIterator for Cloned<I>
+where
+    T: 'a + Clone,
+    I: NonEmptyIterator<Item = &'a T>,
+{
+}
+
+/// A non-empty iterator that copies the items of an underlying iterator.
+///
+/// This `struct` is created by [`NonEmptyIterator::copied`].
+#[derive(Clone, Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Copied<I> {
+    iter: I,
+}
+
+impl<'a, I, T> IntoIterator for Copied<I>
+where
+    T: 'a + Copy,
+    I: NonEmptyIterator<Item = &'a T>,
+{
+    type Item = T;
+    type IntoIter = iter::Copied<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.into_iter().copied()
+    }
+}
+
+unsafe impl<'a, I, T> NonEmptyIterator for Copied<I>
+where
+    T: 'a + Copy,
+    I: NonEmptyIterator<Item = &'a T>,
+{
+}
+
+/// A non-empty double-ended iterator with the direction inverted.
+///
+/// This `struct` is created by [`NonEmptyIterator::rev`].
+#[derive(Clone, Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Rev<I> {
+    iter: I,
+}
+
+impl<I> IntoIterator for Rev<I>
+where
+    I: NonEmptyIterator,
+    I::IntoIter: DoubleEndedIterator,
+{
+    type Item = I::Item;
+    type IntoIter = iter::Rev<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.into_iter().rev()
+    }
+}
+
+unsafe impl<I> NonEmptyIterator for Rev<I>
+where
+    I: NonEmptyIterator,
+    I::IntoIter: DoubleEndedIterator,
+{
+}
+
+/// Non-empty iterator over a non-empty slice.
+///
+/// This `struct` is created by the `nonempty_iter` method on
+/// non-empty slices and vectors.
+#[derive(Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Iter<'a, T> {
+    pub(crate) inner: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for Iter<'a, T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+    }
+}
+
+unsafe impl<'a, T> NonEmptyIterator for Iter<'a, T> {}
+
+/// Non-empty iterator over a non-empty mutable slice.
+///
+/// This `struct` is created by the `nonempty_iter_mut` method on
+/// non-empty slices and vectors.
+#[derive(Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct IterMut<'a, T> {
+    pub(crate) inner: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> IntoIterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+    }
+}
+
+unsafe impl<'a, T> NonEmptyIterator for IterMut<'a, T> {}
+
+/// Owning non-empty iterator over a non-empty vector.
+///
+/// This `struct` is created by `NonEmptyVec::into_nonempty_iter`.
+#[cfg(feature = "vec")]
+#[derive(Clone, Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct IntoIter<T> {
+    pub(crate) inner: alloc::vec::IntoIter<T>,
+}
+
+#[cfg(feature = "vec")]
+impl<T> IntoIterator for IntoIter<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+    }
+}
+
+#[cfg(feature = "vec")]
+unsafe impl<T> NonEmptyIterator for IntoIter<T> {}
@@ -9,16 +9,80 @@ More introduction is in the README.
 #![no_std]
 #![doc(html_root_url = "https://docs.rs/oom/0.3.0")]
 #![warn(rust_2018_idioms)]
+// Trait impls are grouped in named `const` blocks, e.g. `_BUILTIN_TRAITS`.
+#![allow(non_local_definitions)]
 
+#[cfg(feature = "vec")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+#[cfg(feature = "array")]
+mod array;
+#[cfg(feature = "vec")]
+mod binary_heap;
+#[cfg(feature = "vec")]
+mod btree;
+#[cfg(feature = "slice")]
+pub mod chunks;
+mod error;
+#[cfg(feature = "std")]
+mod hash;
+#[cfg(feature = "iter")]
+pub mod iter;
+#[macro_use]
+mod macros;
+#[cfg(all(feature = "rayon", feature = "slice"))]
+pub mod par_iter;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impls;
+#[cfg(all(feature = "serde", feature = "slice"))]
+mod serde_impls;
 #[cfg(feature = "slice")]
 mod slice;
+#[cfg(feature = "smallvec")]
+mod small_vec;
+#[cfg(feature = "string")]
+mod string;
 #[cfg(feature = "vec")]
 mod vec;
+#[cfg(feature = "vec")]
+mod vec_deque;
 
+#[cfg(feature = "array")]
+pub use array::NonEmptyArray;
+#[cfg(feature = "vec")]
+pub use binary_heap::NonEmptyBinaryHeap;
+#[cfg(feature = "vec")]
+pub use btree::{NonEmptyBTreeMap, NonEmptyBTreeSet};
+pub use error::EmptyError;
+#[cfg(feature = "string")]
+pub use error::EmptyStringError;
+#[cfg(feature = "vec")]
+pub use error::{EmptyVecError, RemoveError};
+#[cfg(feature = "std")]
+pub use hash::{NonEmptyHashMap, NonEmptyHashSet};
+#[cfg(feature = "iter")]
+pub use iter::{FromNonEmptyIterator, NonEmptyIterator};
+#[cfg(all(feature = "rayon", feature = "slice"))]
+pub use par_iter::{FromNonEmptyParallelIterator, NonEmptyParallelIterator};
 #[cfg(feature = "slice")]
-pub use slice::share::NonEmptySlice;
-#[cfg(feature = "slice")]
-pub use slice::unique::NonEmptyMutSlice;
-
+pub use slice::NonEmptySlice;
+#[cfg(feature = "smallvec")]
+pub use small_vec::NonEmptySmallVec;
+#[cfg(feature = "string")]
+pub use string::{NonEmptyStr, NonEmptyString};
+#[cfg(feature = "vec")]
+pub use vec::{NonEmptyCow, NonEmptyVec};
 #[cfg(feature = "vec")]
-pub use vec::NonEmptyVec;
+pub use vec_deque::NonEmptyVecDeque;
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "vec")]
+    pub use alloc::vec;
+}
@@ -0,0 +1,106 @@
+/// Creates a [`NonEmptyVec`] containing the arguments.
+///
+/// Like `vec!`, there are two forms of this macro:
+///
+/// - Create a [`NonEmptyVec`] containing a given list of elements:
+///
+/// ```
+/// let v = oom::nonempty![1, 2, 3];
+/// assert_eq!(v.first(), &1);
+/// assert_eq!(v.as_slice(), &[1, 2, 3]);
+/// ```
+///
+/// - Create a [`NonEmptyVec`] from a given element and size:
+///
+/// ```
+/// let v = oom::nonempty![1; 3];
+/// assert_eq!(v.as_slice(), &[1, 1, 1]);
+/// ```
+///
+/// The size must be a constant expression. Empty lists and zero sizes
+/// are rejected at compile time:
+///
+/// ```compile_fail
+/// let v: oom::NonEmptyVec<u8> = oom::nonempty![];
+/// ```
+///
+/// ```compile_fail
+/// let v = oom::nonempty![1; 0];
+/// ```
+///
+/// [`NonEmptyVec`]: crate::NonEmptyVec
+#[cfg(feature = "vec")]
+#[macro_export]
+macro_rules! nonempty {
+    () => {
+        compile_error!("`nonempty!` requires at least one element")
+    };
+    ($elem:expr; $n:expr) => {{
+        // No named items or bindings here: they would clash with the
+        // caller's constants, e.g. `nonempty![0; N]`.
+        const { assert!($n != 0, "`nonempty!` requires a non-zero size") };
+        // SAFETY: The size is checked to be non-zero at compile time.
+        unsafe {
+            $crate::NonEmptyVec::from_vec_unchecked(
+                $crate::__private::vec![$elem; $n],
+            )
+        }
+    }};
+    ($($x:expr),+ $(,)?) => {
+        // SAFETY: The macro pattern requires at least one element.
+        unsafe {
+            $crate::NonEmptyVec::from_vec_unchecked(
+                $crate::__private::vec![$($x),+],
+            )
+        }
+    };
+}
+
+/// Creates a [`&'static NonEmptySlice<T>`] from a constant array.
+///
+/// ```
+/// use oom::NonEmptySlice;
+///
+/// const DIRS: &NonEmptySlice<&str> =
+///     oom::nonempty_slice!["/etc", "/usr/share"];
+/// assert_eq!(DIRS.first(), &"/etc");
+///
+/// let zeros = oom::nonempty_slice![0u8; 4];
+/// assert_eq!(zeros.as_slice(), &[0, 0, 0, 0]);
+/// ```
+///
+/// Elements must be constant expressions. Empty lists and zero sizes
+/// are rejected at compile time:
+///
+/// ```compile_fail
+/// let s: &oom::NonEmptySlice<u8> = oom::nonempty_slice![];
+/// ```
+///
+/// ```compile_fail
+/// let s = oom::nonempty_slice![1; 0];
+/// ```
+///
+/// [`&'static NonEmptySlice<T>`]: crate::NonEmptySlice
+#[cfg(feature = "slice")]
+#[macro_export]
+macro_rules! nonempty_slice {
+    () => {
+        compile_error!("`nonempty_slice!` requires at least one element")
+    };
+    ($elem:expr; $n:expr) => {
+        const {
+            match $crate::NonEmptySlice::from_slice_checked(&[$elem; $n]) {
+                Some(s) => s,
+                None => panic!("`nonempty_slice!` requires a non-zero size"),
+            }
+        }
+    };
+    ($($x:expr),+ $(,)?) => {
+        const {
+            match $crate::NonEmptySlice::from_slice_checked(&[$($x),+]) {
+                Some(s) => s,
+                None => unreachable!(),
+            }
+        }
+    };
+}
@@ -0,0 +1,318 @@
+//! Parallel iterators that are guaranteed to yield at least one item.
+//!
+//! Like [`NonEmptyIterator`](crate::NonEmptyIterator) for [`Iterator`],
+//! a [`NonEmptyParallelIterator`] is not a [`ParallelIterator`] itself.
+//! It can be turned into one with
+//! [`IntoParallelIterator::into_par_iter`] after the non-empty guarantee
+//! is no longer needed.
+
+use core::cmp::Ordering;
+use core::hint::unreachable_unchecked;
+use core::num::NonZeroUsize;
+
+use rayon::iter::{
+    IntoParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator,
+    ParallelIterator,
+};
+use rayon::slice::{Iter, IterMut};
+
+use crate::NonEmptySlice;
+
+/// A parallel iterator that yields at least one item.
+///
+/// Unlike [`ParallelIterator`], reductions like [`max`] and
+/// [`reduce_with`] return an item directly instead of an `Option`.
+///
+/// ```
+/// use oom::{NonEmptyParallelIterator, NonEmptyVec};
+///
+/// let v = oom::nonempty![3, 1, 4, 1, 5];
+/// assert_eq!(v.par_iter().max(), &5);
+/// assert_eq!(v.par_iter().map(|x| x * 2).reduce_with(|a, b| a + b), 28);
+/// let doubled: NonEmptyVec<_> = v.into_par_iter().map(|x| x * 2).collect();
+/// assert_eq!(doubled, [6, 2, 8, 2, 10]);
+/// ```
+///
+/// # Safety
+///
+/// Implementors must guarantee that the parallel iterator returned by
+/// [`IntoParallelIterator::into_par_iter`] yields at least one item.
+///
+/// [`max`]: NonEmptyParallelIterator::max
+/// [`reduce_with`]: NonEmptyParallelIterator::reduce_with
+pub unsafe trait NonEmptyParallelIterator:
+    IntoParallelIterator + Sized
+{
+    /// Returns the number of items of the iterator.
+    fn count(self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.into_par_iter().count()) }
+    }
+
+    /// Returns the maximum item of the iterator.
+    ///
+    /// If several items are equally maximum, which one is returned
+    /// is not specified.
+    fn max(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        unwrap(self.into_par_iter().max())
+    }
+
+    /// Returns the minimum item of the iterator.
+    ///
+    /// If several items are equally minimum, which one is returned
+    /// is not specified.
+    fn min(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        unwrap(self.into_par_iter().min())
+    }
+
+    /// Returns the item that gives the maximum value from the
+    /// specified function.
+    fn max_by_key<B, F>(self, f: F) -> Self::Item
+    where
+        B: Ord + Send,
+        F: Fn(&Self::Item) -> B + Sync + Send,
+    {
+        unwrap(self.into_par_iter().max_by_key(f))
+    }
+
+    /// Returns the item that gives the minimum value from the
+    /// specified function.
+    fn min_by_key<B, F>(self, f: F) -> Self::Item
+    where
+        B: Ord + Send,
+        F: Fn(&Self::Item) -> B + Sync + Send,
+    {
+        unwrap(self.into_par_iter().min_by_key(f))
+    }
+
+    /// Returns the item that gives the maximum value with respect to the
+    /// specified comparison function.
+    fn max_by<F>(self, compare: F) -> Self::Item
+    where
+        F: Fn(&Self::Item, &Self::Item) -> Ordering + Sync + Send,
+    {
+        unwrap(self.into_par_iter().max_by(compare))
+    }
+
+    /// Returns the item that gives the minimum value with respect to the
+    /// specified comparison function.
+    fn min_by<F>(self, compare: F) -> Self::Item
+    where
+        F: Fn(&Self::Item, &Self::Item) -> Ordering + Sync + Send,
+    {
+        unwrap(self.into_par_iter().min_by(compare))
+    }
+
+    /// Reduces the items to a single one, by repeatedly applying
+    /// a reducing operation in parallel.
+    fn reduce_with<OP>(self, op: OP) -> Self::Item
+    where
+        OP: Fn(Self::Item, Self::Item) -> Self::Item + Sync + Send,
+    {
+        unwrap(self.into_par_iter().reduce_with(op))
+    }
+
+    /// Transforms the iterator into a collection without checking
+    /// whether it is empty.
+    fn collect<B>(self) -> B
+    where
+        B: FromNonEmptyParallelIterator<Self::Item>,
+    {
+        B::from_nonempty_par_iter(self)
+    }
+
+    /// Applies `f` to each item of the iterator in parallel.
+    fn map<B, F>(self, f: F) -> Map<Self, F>
+    where
+        B: Send,
+        F: Fn(Self::Item) -> B + Sync + Send,
+    {
+        Map { iter: self, f }
+    }
+}
+
+/// Conversion from a [`NonEmptyParallelIterator`].
+pub trait FromNonEmptyParallelIterator<A: Send>: Sized {
+    /// Creates a value from a non-empty parallel iterator.
+    fn from_nonempty_par_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyParallelIterator<Item = A>;
+}
+
+#[inline]
+fn unwrap<T>(item: Option<T>) -> T {
+    match item {
+        Some(item) => item,
+        None => unsafe { unreachable_unchecked() },
+    }
+}
+
+/// A non-empty parallel iterator that maps the values of `iter` with `f`.
+///
+/// This `struct` is created by [`NonEmptyParallelIterator::map`].
+#[derive(Clone, Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Map<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<B, I, F> IntoParallelIterator for Map<I, F>
+where
+    I: NonEmptyParallelIterator,
+    B: Send,
+    F: Fn(I::Item) -> B + Sync + Send,
+{
+    type Item = B;
+    type Iter = rayon::iter::Map<I::Iter, F>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.iter.into_par_iter().map(self.f)
+    }
+}
+
+unsafe impl<B, I, F> NonEmptyParallelIterator for Map<I, F>
+where
+    I: NonEmptyParallelIterator,
+    B: Send,
+    F: Fn(I::Item) -> B + Sync + Send,
+{
+}
+
+/// Non-empty parallel iterator over a non-empty slice.
+///
+/// This `struct` is created by [`NonEmptySlice::par_iter`].
+#[derive(Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct ParIter<'a, T: Sync> {
+    inner: Iter<'a, T>,
+}
+
+impl<'a, T: Sync> Clone for ParIter<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T: Sync> IntoParallelIterator for ParIter<'a, T> {
+    type Item = &'a T;
+    type Iter = Iter<'a, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.inner
+    }
+}
+
+unsafe impl<'a, T: Sync> NonEmptyParallelIterator for ParIter<'a, T> {}
+
+/// Non-empty parallel iterator over a non-empty mutable slice.
+///
+/// This `struct` is created by [`NonEmptySlice::par_iter_mut`].
+#[derive(Debug)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct ParIterMut<'a, T: Send> {
+    inner: IterMut<'a, T>,
+}
+
+impl<'a, T: Send> IntoParallelIterator for ParIterMut<'a, T> {
+    type Item = &'a mut T;
+    type Iter = IterMut<'a, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.inner
+    }
+}
+
+unsafe impl<'a, T: Send> NonEmptyParallelIterator for ParIterMut<'a, T> {}
+
+impl<T> NonEmptySlice<T> {
+    /// Returns a non-empty parallel iterator over the slice.
+    pub fn par_iter(&self) -> ParIter<'_, T>
+    where
+        T: Sync,
+    {
+        ParIter {
+            inner: self.as_slice().par_iter(),
+        }
+    }
+
+    /// Returns a non-empty parallel iterator that allows modifying
+    /// each value.
+    ///
+    /// ```
+    /// use oom::{NonEmptyParallelIterator, NonEmptySlice};
+    ///
+    /// let arr = &mut [1, 2, 4];
+    /// let s = NonEmptySlice::from_mut_slice(arr);
+    /// *s.par_iter_mut().max() = 0;
+    /// assert_eq!(arr, &[1, 2, 0]);
+    /// ```
+    pub fn par_iter_mut(&mut self) -> ParIterMut<'_, T>
+    where
+        T: Send,
+    {
+        ParIterMut {
+            inner: self.as_mut_slice().par_iter_mut(),
+        }
+    }
+}
+
+#[cfg(feature = "vec")]
+pub use self::vec::IntoParIter;
+
+#[cfg(feature = "vec")]
+mod vec {
+    use alloc::vec::Vec;
+
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+    use super::{FromNonEmptyParallelIterator, NonEmptyParallelIterator};
+    use crate::NonEmptyVec;
+
+    /// Owning non-empty parallel iterator over a non-empty vector.
+    ///
+    /// This `struct` is created by [`NonEmptyVec::into_par_iter`].
+    #[derive(Clone, Debug)]
+    #[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+    pub struct IntoParIter<T: Send> {
+        inner: rayon::vec::IntoIter<T>,
+    }
+
+    impl<T: Send> IntoParallelIterator for IntoParIter<T> {
+        type Item = T;
+        type Iter = rayon::vec::IntoIter<T>;
+
+        fn into_par_iter(self) -> Self::Iter {
+            self.inner
+        }
+    }
+
+    unsafe impl<T: Send> NonEmptyParallelIterator for IntoParIter<T> {}
+
+    impl<T: Send> FromNonEmptyParallelIterator<T> for NonEmptyVec<T> {
+        fn from_nonempty_par_iter<I>(iter: I) -> Self
+        where
+            I: NonEmptyParallelIterator<Item = T>,
+        {
+            let vec: Vec<T> = iter.into_par_iter().collect();
+            unsafe { Self::from_vec_unchecked(vec) }
+        }
+    }
+
+    impl<T: Send> NonEmptyVec<T> {
+        /// Creates a non-empty parallel iterator which moves all values
+        /// out of the vector.
+        pub fn into_par_iter(self) -> IntoParIter<T> {
+            IntoParIter {
+                inner: self.into_vec().into_par_iter(),
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! [`proptest`] strategies for non-empty collections.
+//!
+//! ```
+//! use oom::proptest::nonempty_vec;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn first_is_not_above_max(v in nonempty_vec(any::<u8>(), 1..=16)) {
+//!         prop_assert!(v.len().get() <= 16);
+//!         prop_assert!(v.first() <= v.iter().max().unwrap());
+//!     }
+//! }
+//! # first_is_not_above_max();
+//! ```
+
+use proptest::arbitrary::{any_with, Arbitrary};
+use proptest::collection::{vec, SizeRange, VecStrategy, VecValueTree};
+use proptest::strategy::{NewTree, Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+
+use crate::NonEmptyVec;
+
+/// Creates a strategy to generate non-empty vectors of elements from
+/// `element`, with a length in `size`.
+///
+/// A lower bound of zero in `size` is raised to one. Shrinking removes
+/// elements down to that lower bound, so it never produces an empty vector.
+///
+/// # Panics
+///
+/// Panics if `size` only allows empty vectors.
+pub fn nonempty_vec<T: Strategy>(
+    element: T,
+    size: impl Into<SizeRange>,
+) -> NonEmptyVecStrategy<T> {
+    let (start, end) = size.into().start_end_incl();
+    assert!(end > 0, "size range must allow at least one element");
+    NonEmptyVecStrategy {
+        inner: vec(element, start.max(1)..=end),
+    }
+}
+
+/// Strategy to create [`NonEmptyVec`]s.
+///
+/// This `struct` is created by [`nonempty_vec`].
+#[derive(Clone, Debug)]
+#[must_use = "strategies do nothing unless used"]
+pub struct NonEmptyVecStrategy<T: Strategy> {
+    inner: VecStrategy<T>,
+}
+
+/// `ValueTree` corresponding to [`NonEmptyVecStrategy`].
+#[derive(Clone, Debug)]
+pub struct NonEmptyVecValueTree<T: ValueTree> {
+    inner: VecValueTree<T>,
+}
+
+impl<T: Strategy> Strategy for NonEmptyVecStrategy<T> {
+    type Tree = NonEmptyVecValueTree<T::Tree>;
+    type Value = NonEmptyVec<T::Value>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let inner = self.inner.new_tree(runner)?;
+        Ok(NonEmptyVecValueTree { inner })
+    }
+}
+
+impl<T: ValueTree> ValueTree for NonEmptyVecValueTree<T> {
+    type Value = NonEmptyVec<T::Value>;
+
+    fn current(&self) -> Self::Value {
+        unsafe { NonEmptyVec::from_vec_unchecked(self.inner.current()) }
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.inner.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.inner.complicate()
+    }
+}
+
+impl<A: Arbitrary> Arbitrary for NonEmptyVec<A> {
+    type Parameters = (SizeRange, A::Parameters);
+    type Strategy = NonEmptyVecStrategy<A::Strategy>;
+
+    fn arbitrary_with((size, params): Self::Parameters) -> Self::Strategy {
+        nonempty_vec(any_with::<A>(params), size)
+    }
+}
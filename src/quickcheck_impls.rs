@@ -0,0 +1,23 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::NonEmptyVec;
+
+impl<T: Arbitrary> Arbitrary for NonEmptyVec<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let len = usize::arbitrary(g) % g.size().max(1) + 1;
+        let vec: Vec<T> = (0..len).map(|_| T::arbitrary(g)).collect();
+        unsafe { Self::from_vec_unchecked(vec) }
+    }
+
+    /// Shrinks like `Vec<T>` does, but stops at a single element.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            self.to_vec()
+                .shrink()
+                .filter_map(|vec| Self::from_vec_checked(vec).ok()),
+        )
+    }
+}
@@ -0,0 +1,133 @@
+#[cfg(feature = "vec")]
+use core::fmt;
+#[cfg(feature = "vec")]
+use core::marker::PhantomData;
+
+#[cfg(feature = "vec")]
+use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::NonEmptySlice;
+#[cfg(feature = "vec")]
+use crate::NonEmptyVec;
+
+impl<T: Serialize> Serialize for NonEmptySlice<T> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self)
+    }
+}
+
+#[cfg(feature = "vec")]
+impl<T: Serialize> Serialize for NonEmptyVec<T> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        self.as_nonempty_slice().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "vec")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NonEmptyVec<T> {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        struct VecVisitor<T> {
+            marker: PhantomData<T>,
+        }
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for VecVisitor<T> {
+            type Value = NonEmptyVec<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a non-empty sequence")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let first = match seq.next_element()? {
+                    Some(first) => first,
+                    None => return Err(A::Error::invalid_length(0, &self)),
+                };
+                // Don't trust the size hint too much, like serde does for `Vec`.
+                let capacity = seq.size_hint().unwrap_or(0).min(4096) + 1;
+                let mut vec = alloc::vec::Vec::with_capacity(capacity);
+                vec.push(first);
+                while let Some(elem) = seq.next_element()? {
+                    vec.push(elem);
+                }
+                Ok(unsafe { NonEmptyVec::from_vec_unchecked(vec) })
+            }
+        }
+
+        deserializer.deserialize_seq(VecVisitor {
+            marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "string")]
+const _STRING: () = {
+    use alloc::string::String;
+
+    use crate::{NonEmptyStr, NonEmptyString};
+
+    impl Serialize for NonEmptyStr {
+        fn serialize<S: Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl Serialize for NonEmptyString {
+        fn serialize<S: Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for NonEmptyString {
+        fn deserialize<D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            struct StringVisitor;
+
+            impl<'de> Visitor<'de> for StringVisitor {
+                type Value = NonEmptyString;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("a non-empty string")
+                }
+
+                fn visit_str<E: Error>(
+                    self,
+                    v: &str,
+                ) -> Result<Self::Value, E> {
+                    match NonEmptyStr::from_str_checked(v) {
+                        Some(s) => Ok(NonEmptyString::from(s)),
+                        None => Err(E::invalid_length(0, &self)),
+                    }
+                }
+
+                fn visit_string<E: Error>(
+                    self,
+                    v: String,
+                ) -> Result<Self::Value, E> {
+                    NonEmptyString::from_string_checked(v)
+                        .map_err(|_| E::invalid_length(0, &self))
+                }
+            }
+
+            deserializer.deserialize_string(StringVisitor)
+        }
+    }
+};
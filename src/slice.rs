@@ -1,2 +1,735 @@
-pub(crate) mod share;
-pub(crate) mod unique;
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::hint::unreachable_unchecked;
+use core::mem::size_of;
+use core::num::NonZeroUsize;
+use core::ops::{Index, IndexMut};
+use core::slice::{self, SliceIndex};
+
+use crate::EmptyError;
+
+/// A non-empty slice type, counterpart of `[T]`.
+///
+/// It is an unsized type, used behind a pointer like `&NonEmptySlice<T>`,
+/// `&mut NonEmptySlice<T>` or `Box<NonEmptySlice<T>>`.
+/// Like any reference, a `&mut NonEmptySlice<T>` can be reborrowed with
+/// `&mut *s` to pass it to a function and keep using it, or with `&*s`
+/// to get a shared `&NonEmptySlice<T>`.
+#[repr(transparent)]
+pub struct NonEmptySlice<T> {
+    inner: [T],
+}
+
+const _SIZE: () = {
+    const FOO: [(); 1] = [()];
+    const SIZE: usize = size_of::<&NonEmptySlice<&str>>();
+    #[cfg(target_pointer_width = "64")]
+    let idx = !(SIZE == 16) as usize;
+    #[cfg(target_pointer_width = "32")]
+    let idx = !(SIZE == 8) as usize;
+    FOO[idx]
+};
+
+const _BUILTIN_TRAITS: () = {
+    impl<T: Eq> Eq for NonEmptySlice<T> {}
+
+    impl<T: PartialEq> PartialEq for NonEmptySlice<T> {
+        fn eq(&self, other: &Self) -> bool {
+            self.as_slice().eq(other.as_slice())
+        }
+    }
+
+    impl<T: Ord> Ord for NonEmptySlice<T> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.as_slice().cmp(other.as_slice())
+        }
+    }
+
+    impl<T: PartialOrd> PartialOrd for NonEmptySlice<T> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.as_slice().partial_cmp(other.as_slice())
+        }
+    }
+
+    impl<T> AsRef<[T]> for NonEmptySlice<T> {
+        fn as_ref(&self) -> &[T] {
+            self.as_slice()
+        }
+    }
+
+    impl<T> AsMut<[T]> for NonEmptySlice<T> {
+        fn as_mut(&mut self) -> &mut [T] {
+            self.as_mut_slice()
+        }
+    }
+
+    impl<T> Borrow<[T]> for NonEmptySlice<T> {
+        fn borrow(&self) -> &[T] {
+            self.as_slice()
+        }
+    }
+
+    impl<T> BorrowMut<[T]> for NonEmptySlice<T> {
+        fn borrow_mut(&mut self) -> &mut [T] {
+            self.as_mut_slice()
+        }
+    }
+
+    impl<T: Hash> Hash for NonEmptySlice<T> {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.as_slice().hash(state)
+        }
+    }
+
+    impl<T: fmt::Debug> fmt::Debug for NonEmptySlice<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.as_slice().fmt(f)
+        }
+    }
+
+    impl<T, I: SliceIndex<[T]>> Index<I> for NonEmptySlice<T> {
+        type Output = I::Output;
+
+        fn index(&self, index: I) -> &Self::Output {
+            self.as_slice().index(index)
+        }
+    }
+
+    impl<T, I: SliceIndex<[T]>> IndexMut<I> for NonEmptySlice<T> {
+        fn index_mut(&mut self, index: I) -> &mut Self::Output {
+            self.as_mut_slice().index_mut(index)
+        }
+    }
+
+    impl<T: PartialEq<U>, U> PartialEq<[U]> for NonEmptySlice<T> {
+        fn eq(&self, other: &[U]) -> bool {
+            self.as_slice() == other
+        }
+    }
+
+    impl<T: PartialEq<U>, U> PartialEq<NonEmptySlice<U>> for [T] {
+        fn eq(&self, other: &NonEmptySlice<U>) -> bool {
+            self == other.as_slice()
+        }
+    }
+
+    impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]>
+        for NonEmptySlice<T>
+    {
+        fn eq(&self, other: &[U; N]) -> bool {
+            self.as_slice() == other
+        }
+    }
+
+    impl<T: PartialEq<U>, U, const N: usize> PartialEq<NonEmptySlice<U>>
+        for [T; N]
+    {
+        fn eq(&self, other: &NonEmptySlice<U>) -> bool {
+            self == other.as_slice()
+        }
+    }
+
+    impl<'a, T> From<&'a NonEmptySlice<T>> for &'a [T] {
+        fn from(slice: &'a NonEmptySlice<T>) -> Self {
+            slice.as_slice()
+        }
+    }
+
+    impl<'a, T> From<&'a mut NonEmptySlice<T>> for &'a mut [T] {
+        fn from(slice: &'a mut NonEmptySlice<T>) -> Self {
+            slice.as_mut_slice()
+        }
+    }
+
+    impl<'a, T> TryFrom<&'a [T]> for &'a NonEmptySlice<T> {
+        type Error = EmptyError;
+
+        fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+            NonEmptySlice::from_slice_checked(slice).ok_or(EmptyError)
+        }
+    }
+
+    impl<'a, T> TryFrom<&'a mut [T]> for &'a mut NonEmptySlice<T> {
+        type Error = EmptyError;
+
+        fn try_from(slice: &'a mut [T]) -> Result<Self, Self::Error> {
+            NonEmptySlice::from_mut_slice_checked(slice).ok_or(EmptyError)
+        }
+    }
+
+    impl<'a, T> IntoIterator for &'a NonEmptySlice<T> {
+        type Item = &'a T;
+        type IntoIter = slice::Iter<'a, T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<'a, T> IntoIterator for &'a mut NonEmptySlice<T> {
+        type Item = &'a mut T;
+        type IntoIter = slice::IterMut<'a, T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+};
+
+impl<T> NonEmptySlice<T> {
+    /// Converts a `&T` into a `&NonEmptySlice`.
+    pub fn from_ref(e: &T) -> &Self {
+        unsafe { Self::from_slice_unchecked(slice::from_ref(e)) }
+    }
+
+    /// Converts a `&mut T` into a `&mut NonEmptySlice`.
+    pub fn from_mut(e: &mut T) -> &mut Self {
+        unsafe { Self::from_mut_slice_unchecked(slice::from_mut(e)) }
+    }
+
+    /// Converts a `&[T]` into a `&NonEmptySlice`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the passed slice is empty.
+    pub fn from_slice(slice: &[T]) -> &Self {
+        Self::from_slice_checked(slice).expect("slice shouldn't be empty")
+    }
+
+    /// Converts a `&[T]` into a `&NonEmptySlice`.
+    /// Returns `None` if the passed slice is empty.
+    pub const fn from_slice_checked(slice: &[T]) -> Option<&Self> {
+        if slice.is_empty() {
+            return None;
+        }
+
+        Some(unsafe { Self::from_slice_unchecked(slice) })
+    }
+
+    /// Converts a `&mut [T]` into a `&mut NonEmptySlice`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the passed slice is empty.
+    pub fn from_mut_slice(slice: &mut [T]) -> &mut Self {
+        Self::from_mut_slice_checked(slice).expect("slice shouldn't be empty")
+    }
+
+    /// Converts a `&mut [T]` into a `&mut NonEmptySlice`.
+    /// Returns `None` if the passed slice is empty.
+    pub fn from_mut_slice_checked(slice: &mut [T]) -> Option<&mut Self> {
+        if slice.is_empty() {
+            return None;
+        }
+
+        Some(unsafe { Self::from_mut_slice_unchecked(slice) })
+    }
+
+    /// Converts a `&[T]` into a `&NonEmptySlice` without checking
+    /// that it is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The passed slice must not be empty.
+    pub const unsafe fn from_slice_unchecked(slice: &[T]) -> &Self {
+        debug_assert!(!slice.is_empty());
+        // SAFETY: `NonEmptySlice<T>` is `repr(transparent)` over `[T]`.
+        &*(slice as *const [T] as *const Self)
+    }
+
+    /// Converts a `&mut [T]` into a `&mut NonEmptySlice` without checking
+    /// that it is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The passed slice must not be empty.
+    pub unsafe fn from_mut_slice_unchecked(slice: &mut [T]) -> &mut Self {
+        debug_assert!(!slice.is_empty());
+        // SAFETY: `NonEmptySlice<T>` is `repr(transparent)` over `[T]`.
+        &mut *(slice as *mut [T] as *mut Self)
+    }
+
+    /// Returns a raw pointer to the slice's buffer.
+    pub const fn as_ptr(&self) -> *const T {
+        self.inner.as_ptr()
+    }
+
+    /// Returns an unsafe mutable pointer to the slice's buffer.
+    ///
+    /// The caller must ensure that the slice outlives the pointer
+    /// this function returns, or else it will end up pointing to garbage.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.inner.as_mut_ptr()
+    }
+
+    /// Returns a `&[T]` containing entire `NonEmptySlice`.
+    pub const fn as_slice(&self) -> &[T] {
+        &self.inner
+    }
+
+    /// Returns a mutable slice from this type.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+
+    /// Returns an iterator over the slice.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let s = NonEmptySlice::from_slice(&[1, 2, 4]);
+    /// let mut iterator = s.iter();
+    /// assert_eq!(iterator.size_hint(), (3, Some(3)));
+    /// assert_eq!(iterator.next(), Some(&1));
+    /// assert_eq!(iterator.next(), Some(&2));
+    /// assert_eq!(iterator.next(), Some(&4));
+    /// assert_eq!(iterator.next(), None);
+    /// ```
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.inner.iter()
+    }
+
+    /// Returns an iterator that allows modifying each value.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let arr = &mut [1, 2, 4];
+    /// let s = NonEmptySlice::from_mut_slice(arr);
+    /// for elem in s.iter_mut() {
+    ///     *elem += 2;
+    /// }
+    /// assert_eq!(arr, &[3, 4, 6]);
+    /// ```
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.inner.iter_mut()
+    }
+
+    /// Returns a non-empty iterator over the slice.
+    #[cfg(feature = "iter")]
+    pub fn nonempty_iter(&self) -> crate::iter::Iter<'_, T> {
+        crate::iter::Iter {
+            inner: self.inner.iter(),
+        }
+    }
+
+    /// Returns a non-empty iterator that allows modifying each value.
+    ///
+    /// ```
+    /// use oom::{NonEmptyIterator, NonEmptySlice};
+    ///
+    /// let arr = &mut [1, 2, 4];
+    /// let s = NonEmptySlice::from_mut_slice(arr);
+    /// *s.nonempty_iter_mut().max() = 0;
+    /// assert_eq!(arr, &[1, 2, 0]);
+    /// ```
+    #[cfg(feature = "iter")]
+    pub fn nonempty_iter_mut(&mut self) -> crate::iter::IterMut<'_, T> {
+        crate::iter::IterMut {
+            inner: self.inner.iter_mut(),
+        }
+    }
+
+    /// Returns the number of elements in the slice.
+    pub const fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.inner.len()) }
+    }
+
+    /// Always returns `false` because the slice is non-empty.
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the first element of the slice.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let s = NonEmptySlice::from_slice(&[10, 40, 30]);
+    /// assert_eq!(s.first(), &10);
+    /// ```
+    pub fn first(&self) -> &T {
+        match &self.inner {
+            [first, ..] => first,
+            [] => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns a mutable pointer to the first element of the slice.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let arr = &mut [10, 40, 30];
+    /// let s = NonEmptySlice::from_mut_slice(arr);
+    /// *s.first_mut() = 42;
+    /// assert_eq!(arr, &[42, 40, 30]);
+    /// ```
+    pub fn first_mut(&mut self) -> &mut T {
+        match &mut self.inner {
+            [first, ..] => first,
+            [] => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns the last element of the slice.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let s = NonEmptySlice::from_slice(&[10, 40, 30]);
+    /// assert_eq!(s.last(), &30);
+    /// ```
+    pub fn last(&self) -> &T {
+        match &self.inner {
+            [.., last] => last,
+            [] => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns a mutable pointer to the last element of the slice.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let arr = &mut [10, 40, 30];
+    /// let s = NonEmptySlice::from_mut_slice(arr);
+    /// *s.last_mut() = 42;
+    /// assert_eq!(arr, &[10, 40, 42]);
+    /// ```
+    pub fn last_mut(&mut self) -> &mut T {
+        match &mut self.inner {
+            [.., last] => last,
+            [] => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns the first and all the rest of the elements of the slice.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let s = NonEmptySlice::from_slice(&[10, 40, 30]);
+    /// assert_eq!(s.split_first(), (&10, &[40, 30][..]));
+    /// ```
+    pub fn split_first(&self) -> (&T, &[T]) {
+        match &self.inner {
+            [first, rest @ ..] => (first, rest),
+            [] => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns the first and all the rest of the elements of the slice.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let arr = &mut [0, 1, 2];
+    /// let s = NonEmptySlice::from_mut_slice(arr);
+    /// let (first, rest) = s.split_first_mut();
+    /// *first = 3;
+    /// rest[0] = 4;
+    /// rest[1] = 5;
+    /// assert_eq!(arr, &[3, 4, 5]);
+    /// ```
+    pub fn split_first_mut(&mut self) -> (&mut T, &mut [T]) {
+        match &mut self.inner {
+            [first, rest @ ..] => (first, rest),
+            [] => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns the last and all the rest of the elements of the slice.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let s = NonEmptySlice::from_slice(&[10, 40, 30]);
+    /// assert_eq!(s.split_last(), (&30, &[10, 40][..]));
+    /// ```
+    pub fn split_last(&self) -> (&T, &[T]) {
+        match &self.inner {
+            [rest @ .., last] => (last, rest),
+            [] => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns the last and all the rest of the elements of the slice.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let arr = &mut [0, 1, 2];
+    /// let s = NonEmptySlice::from_mut_slice(arr);
+    /// let (last, rest) = s.split_last_mut();
+    /// *last = 3;
+    /// rest[0] = 4;
+    /// rest[1] = 5;
+    /// assert_eq!(arr, &[4, 5, 3]);
+    /// ```
+    pub fn split_last_mut(&mut self) -> (&mut T, &mut [T]) {
+        match &mut self.inner {
+            [rest @ .., last] => (last, rest),
+            [] => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns the first element and the rest of the slice,
+    /// or `None` if there is no more element.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// fn sum(s: &NonEmptySlice<i32>) -> i32 {
+    ///     match s.uncons() {
+    ///         (head, Some(tail)) => head + sum(tail),
+    ///         (head, None) => *head,
+    ///     }
+    /// }
+    /// assert_eq!(sum(NonEmptySlice::from_slice(&[1, 2, 3])), 6);
+    /// ```
+    pub fn uncons(&self) -> (&T, Option<&Self>) {
+        let (first, rest) = self.split_first();
+        (first, Self::from_slice_checked(rest))
+    }
+
+    /// Returns the first element and the rest of the mutable slice,
+    /// or `None` if there is no more element.
+    pub fn uncons_mut(&mut self) -> (&mut T, Option<&mut Self>) {
+        let (first, rest) = self.split_first_mut();
+        (first, Self::from_mut_slice_checked(rest))
+    }
+
+    /// Returns the last element and the rest of the slice,
+    /// or `None` if there is no more element.
+    pub fn unsnoc(&self) -> (&T, Option<&Self>) {
+        let (last, rest) = self.split_last();
+        (last, Self::from_slice_checked(rest))
+    }
+
+    /// Returns the last element and the rest of the mutable slice,
+    /// or `None` if there is no more element.
+    pub fn unsnoc_mut(&mut self) -> (&mut T, Option<&mut Self>) {
+        let (last, rest) = self.split_last_mut();
+        (last, Self::from_mut_slice_checked(rest))
+    }
+
+    /// Divides the slice into a non-empty head of `mid` elements
+    /// and the rest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the slice.
+    ///
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// # use oom::NonEmptySlice;
+    /// let s = NonEmptySlice::from_slice(&[1, 2, 3]);
+    /// let (head, tail) = s.split_at(NonZeroUsize::new(1).unwrap());
+    /// assert_eq!((head.first(), tail), (&1, &[2, 3][..]));
+    /// ```
+    pub fn split_at(&self, mid: NonZeroUsize) -> (&Self, &[T]) {
+        let (head, tail) = self.inner.split_at(mid.get());
+        (unsafe { Self::from_slice_unchecked(head) }, tail)
+    }
+
+    /// Divides the mutable slice into a non-empty head of `mid` elements
+    /// and the rest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the slice.
+    pub fn split_at_mut(&mut self, mid: NonZeroUsize) -> (&mut Self, &mut [T]) {
+        let (head, tail) = self.inner.split_at_mut(mid.get());
+        (unsafe { Self::from_mut_slice_unchecked(head) }, tail)
+    }
+
+    /// Divides the slice into two non-empty halves at `mid`.
+    /// Returns `None` unless `mid` is strictly less than the length.
+    ///
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// # use oom::NonEmptySlice;
+    /// let s = NonEmptySlice::from_slice(&[1, 2, 3]);
+    /// let (head, tail) = s.split_at_nonempty(NonZeroUsize::new(2).unwrap()).unwrap();
+    /// assert_eq!((head.last(), tail.first()), (&2, &3));
+    /// assert!(s.split_at_nonempty(NonZeroUsize::new(3).unwrap()).is_none());
+    /// ```
+    pub fn split_at_nonempty(
+        &self,
+        mid: NonZeroUsize,
+    ) -> Option<(&Self, &Self)> {
+        if mid >= self.len() {
+            return None;
+        }
+
+        let (head, tail) = self.inner.split_at(mid.get());
+        Some(unsafe {
+            (
+                Self::from_slice_unchecked(head),
+                Self::from_slice_unchecked(tail),
+            )
+        })
+    }
+
+    /// Divides the mutable slice into two non-empty halves at `mid`.
+    /// Returns `None` unless `mid` is strictly less than the length.
+    pub fn split_at_nonempty_mut(
+        &mut self,
+        mid: NonZeroUsize,
+    ) -> Option<(&mut Self, &mut Self)> {
+        if mid >= self.len() {
+            return None;
+        }
+
+        let (head, tail) = self.inner.split_at_mut(mid.get());
+        Some(unsafe {
+            (
+                Self::from_mut_slice_unchecked(head),
+                Self::from_mut_slice_unchecked(tail),
+            )
+        })
+    }
+
+    /// Swaps two elements in the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.inner.swap(a, b)
+    }
+
+    /// Reverses the order of elements in the slice, in place.
+    pub fn reverse(&mut self) {
+        self.inner.reverse()
+    }
+
+    /// Rotates the slice in-place such that the first `mid` elements
+    /// move to the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the slice.
+    pub fn rotate_left(&mut self, mid: usize) {
+        self.inner.rotate_left(mid)
+    }
+
+    /// Rotates the slice in-place such that the last `k` elements
+    /// move to the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the length of the slice.
+    pub fn rotate_right(&mut self, k: usize) {
+        self.inner.rotate_right(k)
+    }
+
+    /// Sorts the slice, preserving the order of equal elements.
+    ///
+    /// After sorting, [`first`](Self::first) and [`last`](Self::last)
+    /// are the minimum and the maximum.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let arr = &mut [3, 1, 2];
+    /// let s = NonEmptySlice::from_mut_slice(arr);
+    /// s.sort();
+    /// assert_eq!((s.first(), s.last()), (&1, &3));
+    /// ```
+    #[cfg(feature = "vec")]
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.inner.sort()
+    }
+
+    /// Sorts the slice with a comparator function, preserving the order
+    /// of equal elements.
+    #[cfg(feature = "vec")]
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.inner.sort_by(compare)
+    }
+
+    /// Sorts the slice with a key extraction function, preserving
+    /// the order of equal elements.
+    #[cfg(feature = "vec")]
+    pub fn sort_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.inner.sort_by_key(f)
+    }
+
+    /// Sorts the slice, but might not preserve the order of equal elements.
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.inner.sort_unstable()
+    }
+
+    /// Sorts the slice with a comparator function, but might not
+    /// preserve the order of equal elements.
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.inner.sort_unstable_by(compare)
+    }
+
+    /// Sorts the slice with a key extraction function, but might not
+    /// preserve the order of equal elements.
+    pub fn sort_unstable_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.inner.sort_unstable_by_key(f)
+    }
+
+    /// Binary searches this sorted slice for a given element.
+    ///
+    /// Returns `Ok` with the index of a matching element, or `Err` with
+    /// the index where it could be inserted while keeping the order.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.inner.binary_search(x)
+    }
+
+    /// Binary searches this sorted slice with a comparator function.
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        self.inner.binary_search_by(f)
+    }
+
+    /// Binary searches this sorted slice with a key extraction function.
+    pub fn binary_search_by_key<B, F>(
+        &self,
+        b: &B,
+        f: F,
+    ) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.inner.binary_search_by_key(b, f)
+    }
+
+    /// Returns the index of the partition point according to
+    /// the given predicate, that is, the index of the first element
+    /// for which `pred` returns `false`.
+    ///
+    /// ```
+    /// # use oom::NonEmptySlice;
+    /// let s = NonEmptySlice::from_slice(&[1, 2, 3, 5, 8]);
+    /// assert_eq!(s.partition_point(|&x| x < 4), 3);
+    /// ```
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.inner.partition_point(pred)
+    }
+}
@@ -0,0 +1,356 @@
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::num::NonZeroUsize;
+use core::ops::{Deref, DerefMut};
+
+use smallvec::{Array, SmallVec};
+
+use crate::{EmptyError, NonEmptySlice, NonEmptyVec};
+
+/// A non-empty vector that stores up to `A::size()` elements inline,
+/// counterpart of `SmallVec<A>`.
+///
+/// It dereferences to [`NonEmptySlice`], like [`NonEmptyVec`].
+///
+/// ```
+/// # use oom::NonEmptySmallVec;
+/// let mut v = NonEmptySmallVec::<[u8; 2]>::new(1);
+/// v.push(2);
+/// assert!(!v.spilled());
+/// v.push(3);
+/// assert!(v.spilled());
+/// assert_eq!(v.first(), &1);
+/// assert_eq!(v.len().get(), 3);
+/// assert_eq!(v.pop(), Some(3));
+/// assert_eq!(v.pop(), Some(2));
+/// assert_eq!(v.pop(), None);
+/// ```
+pub struct NonEmptySmallVec<A: Array> {
+    inner: SmallVec<A>,
+}
+
+const _BUILTIN_TRAITS: () = {
+    impl<A: Array> Clone for NonEmptySmallVec<A>
+    where
+        A::Item: Clone,
+    {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+            }
+        }
+    }
+
+    impl<A: Array> Eq for NonEmptySmallVec<A> where A::Item: Eq {}
+
+    impl<A: Array> PartialEq for NonEmptySmallVec<A>
+    where
+        A::Item: PartialEq,
+    {
+        fn eq(&self, other: &Self) -> bool {
+            self.as_slice().eq(other.as_slice())
+        }
+    }
+
+    impl<A: Array> Ord for NonEmptySmallVec<A>
+    where
+        A::Item: Ord,
+    {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.as_slice().cmp(other.as_slice())
+        }
+    }
+
+    impl<A: Array> PartialOrd for NonEmptySmallVec<A>
+    where
+        A::Item: PartialOrd,
+    {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.as_slice().partial_cmp(other.as_slice())
+        }
+    }
+
+    impl<A: Array, U, const N: usize> PartialEq<[U; N]> for NonEmptySmallVec<A>
+    where
+        A::Item: PartialEq<U>,
+    {
+        fn eq(&self, other: &[U; N]) -> bool {
+            self.as_slice() == other
+        }
+    }
+
+    impl<A: Array> Hash for NonEmptySmallVec<A>
+    where
+        A::Item: Hash,
+    {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.as_slice().hash(state)
+        }
+    }
+
+    impl<A: Array> fmt::Debug for NonEmptySmallVec<A>
+    where
+        A::Item: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.as_slice().fmt(f)
+        }
+    }
+
+    impl<A: Array> Deref for NonEmptySmallVec<A> {
+        type Target = NonEmptySlice<A::Item>;
+
+        fn deref(&self) -> &Self::Target {
+            self.as_nonempty_slice()
+        }
+    }
+
+    impl<A: Array> DerefMut for NonEmptySmallVec<A> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            self.as_nonempty_mut_slice()
+        }
+    }
+
+    impl<A: Array> AsRef<[A::Item]> for NonEmptySmallVec<A> {
+        fn as_ref(&self) -> &[A::Item] {
+            self.as_slice()
+        }
+    }
+
+    impl<A: Array> AsMut<[A::Item]> for NonEmptySmallVec<A> {
+        fn as_mut(&mut self) -> &mut [A::Item] {
+            self.as_mut_slice()
+        }
+    }
+
+    impl<A: Array> AsRef<NonEmptySlice<A::Item>> for NonEmptySmallVec<A> {
+        fn as_ref(&self) -> &NonEmptySlice<A::Item> {
+            self.as_nonempty_slice()
+        }
+    }
+
+    impl<A: Array> AsMut<NonEmptySlice<A::Item>> for NonEmptySmallVec<A> {
+        fn as_mut(&mut self) -> &mut NonEmptySlice<A::Item> {
+            self.as_nonempty_mut_slice()
+        }
+    }
+
+    impl<A: Array> Borrow<NonEmptySlice<A::Item>> for NonEmptySmallVec<A> {
+        fn borrow(&self) -> &NonEmptySlice<A::Item> {
+            self.as_nonempty_slice()
+        }
+    }
+
+    impl<A: Array> BorrowMut<NonEmptySlice<A::Item>> for NonEmptySmallVec<A> {
+        fn borrow_mut(&mut self) -> &mut NonEmptySlice<A::Item> {
+            self.as_nonempty_mut_slice()
+        }
+    }
+
+    impl<A: Array> TryFrom<SmallVec<A>> for NonEmptySmallVec<A> {
+        type Error = EmptyError;
+
+        fn try_from(vec: SmallVec<A>) -> Result<Self, Self::Error> {
+            Self::from_smallvec_checked(vec).map_err(|_| EmptyError)
+        }
+    }
+
+    impl<A: Array> From<NonEmptySmallVec<A>> for SmallVec<A> {
+        fn from(vec: NonEmptySmallVec<A>) -> Self {
+            vec.inner
+        }
+    }
+
+    impl<A: Array> From<NonEmptyVec<A::Item>> for NonEmptySmallVec<A> {
+        fn from(vec: NonEmptyVec<A::Item>) -> Self {
+            Self {
+                inner: SmallVec::from_vec(vec.into_vec()),
+            }
+        }
+    }
+
+    impl<A: Array> From<NonEmptySmallVec<A>> for NonEmptyVec<A::Item> {
+        fn from(vec: NonEmptySmallVec<A>) -> Self {
+            vec.into_vec()
+        }
+    }
+
+    impl<A: Array> From<&NonEmptySlice<A::Item>> for NonEmptySmallVec<A>
+    where
+        A::Item: Clone,
+    {
+        fn from(slice: &NonEmptySlice<A::Item>) -> Self {
+            Self {
+                inner: SmallVec::from(slice.as_slice()),
+            }
+        }
+    }
+
+    impl<A: Array> IntoIterator for NonEmptySmallVec<A> {
+        type Item = A::Item;
+        type IntoIter = smallvec::IntoIter<A>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.inner.into_iter()
+        }
+    }
+
+    impl<'a, A: Array> IntoIterator for &'a NonEmptySmallVec<A> {
+        type Item = &'a A::Item;
+        type IntoIter = core::slice::Iter<'a, A::Item>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<'a, A: Array> IntoIterator for &'a mut NonEmptySmallVec<A> {
+        type Item = &'a mut A::Item;
+        type IntoIter = core::slice::IterMut<'a, A::Item>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+
+    #[cfg(feature = "iter")]
+    impl<A: Array> crate::FromNonEmptyIterator<A::Item> for NonEmptySmallVec<A> {
+        fn from_nonempty_iter<I>(iter: I) -> Self
+        where
+            I: crate::NonEmptyIterator<Item = A::Item>,
+        {
+            Self {
+                inner: iter.into_iter().collect(),
+            }
+        }
+    }
+
+    impl<A: Array> Extend<A::Item> for NonEmptySmallVec<A> {
+        fn extend<I: IntoIterator<Item = A::Item>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
+};
+
+impl<A: Array> NonEmptySmallVec<A> {
+    /// Constructs a new `NonEmptySmallVec` containing a single element.
+    pub fn new(first: A::Item) -> Self {
+        let mut inner = SmallVec::new();
+        inner.push(first);
+        Self { inner }
+    }
+
+    /// Converts a `SmallVec<A>` into a `NonEmptySmallVec`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if passed `SmallVec` is empty.
+    pub fn from_smallvec(vec: SmallVec<A>) -> Self {
+        match Self::from_smallvec_checked(vec) {
+            Ok(v) => v,
+            Err(_) => panic!("smallvec shouldn't be empty"),
+        }
+    }
+
+    /// Converts a `SmallVec<A>` into a `NonEmptySmallVec`.
+    /// Returns passed `SmallVec` if it is empty.
+    pub fn from_smallvec_checked(
+        vec: SmallVec<A>,
+    ) -> Result<Self, SmallVec<A>> {
+        if vec.is_empty() {
+            return Err(vec);
+        }
+        Ok(Self { inner: vec })
+    }
+
+    /// Converts a `SmallVec<A>` into a `NonEmptySmallVec` without
+    /// checking that it is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The passed `SmallVec` must not be empty.
+    pub unsafe fn from_smallvec_unchecked(vec: SmallVec<A>) -> Self {
+        debug_assert!(!vec.is_empty());
+        Self { inner: vec }
+    }
+
+    /// Returns a reference to the inner `SmallVec`.
+    pub fn as_smallvec(&self) -> &SmallVec<A> {
+        &self.inner
+    }
+
+    /// Converts `self` into the inner `SmallVec`.
+    pub fn into_smallvec(self) -> SmallVec<A> {
+        self.inner
+    }
+
+    /// Returns a non-empty slice from this vector.
+    pub fn as_nonempty_slice(&self) -> &NonEmptySlice<A::Item> {
+        unsafe { NonEmptySlice::from_slice_unchecked(&self.inner) }
+    }
+
+    /// Returns a non-empty mutable slice from this vector.
+    pub fn as_nonempty_mut_slice(&mut self) -> &mut NonEmptySlice<A::Item> {
+        unsafe { NonEmptySlice::from_mut_slice_unchecked(&mut self.inner) }
+    }
+
+    /// Converts `self` into a `NonEmptyVec`, without reallocating
+    /// if the elements are already on the heap.
+    pub fn into_vec(self) -> NonEmptyVec<A::Item> {
+        unsafe { NonEmptyVec::from_vec_unchecked(self.inner.into_vec()) }
+    }
+
+    /// Returns the number of elements that can be stored inline.
+    pub fn inline_size(&self) -> usize {
+        self.inner.inline_size()
+    }
+
+    /// Returns `true` if the elements have been moved to the heap.
+    pub fn spilled(&self) -> bool {
+        self.inner.spilled()
+    }
+
+    /// Returns the number of elements the vector can hold without
+    /// reallocating.
+    pub fn capacity(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.inner.capacity()) }
+    }
+
+    /// Appends an element to the back of the vector.
+    pub fn push(&mut self, value: A::Item) {
+        self.inner.push(value);
+    }
+
+    /// Removes the last element and returns it,
+    /// or `None` if it is the only element left.
+    pub fn pop(&mut self) -> Option<A::Item> {
+        if self.inner.len() == 1 {
+            return None;
+        }
+        self.inner.pop()
+    }
+
+    /// Inserts an element at position `index`, shifting all elements
+    /// after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, element: A::Item) {
+        self.inner.insert(index, element);
+    }
+
+    /// Shortens the vector, keeping the first `len` elements.
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        self.inner.truncate(len.get());
+    }
+
+    /// Shrinks the capacity as much as possible, moving the elements
+    /// back inline if they fit.
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit();
+    }
+}
@@ -0,0 +1,429 @@
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::hint::unreachable_unchecked;
+use core::mem::size_of;
+use core::num::NonZeroUsize;
+use core::ops::{Deref, DerefMut};
+use core::str::{self, FromStr};
+
+use crate::{EmptyError, EmptyStringError, NonEmptySlice};
+
+/// A non-empty string slice type, counterpart of `str`.
+///
+/// Like [`NonEmptySlice`], it is an unsized type, used as `&NonEmptyStr`.
+#[repr(transparent)]
+pub struct NonEmptyStr {
+    inner: str,
+}
+
+/// A non-empty string type, counterpart of `String`.
+#[derive(Clone)]
+pub struct NonEmptyString {
+    inner: String,
+}
+
+const _SIZE: () = {
+    const FOO: [(); 1] = [()];
+    const SIZE: usize = size_of::<&NonEmptyStr>() + size_of::<NonEmptyString>();
+    #[cfg(target_pointer_width = "64")]
+    let idx = !(SIZE == 16 + 24) as usize;
+    #[cfg(target_pointer_width = "32")]
+    let idx = !(SIZE == 8 + 12) as usize;
+    FOO[idx]
+};
+
+const _BUILTIN_TRAITS: () = {
+    impl Eq for NonEmptyStr {}
+
+    impl PartialEq for NonEmptyStr {
+        fn eq(&self, other: &Self) -> bool {
+            self.as_str().eq(other.as_str())
+        }
+    }
+
+    impl Ord for NonEmptyStr {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.as_str().cmp(other.as_str())
+        }
+    }
+
+    impl PartialOrd for NonEmptyStr {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Hash for NonEmptyStr {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.as_str().hash(state)
+        }
+    }
+
+    impl AsRef<str> for NonEmptyStr {
+        fn as_ref(&self) -> &str {
+            self.as_str()
+        }
+    }
+
+    impl AsRef<[u8]> for NonEmptyStr {
+        fn as_ref(&self) -> &[u8] {
+            self.as_str().as_bytes()
+        }
+    }
+
+    impl fmt::Debug for NonEmptyStr {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(self.as_str(), f)
+        }
+    }
+
+    impl fmt::Display for NonEmptyStr {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Display::fmt(self.as_str(), f)
+        }
+    }
+
+    impl Eq for NonEmptyString {}
+
+    impl PartialEq for NonEmptyString {
+        fn eq(&self, other: &Self) -> bool {
+            self.as_str().eq(other.as_str())
+        }
+    }
+
+    impl Ord for NonEmptyString {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.as_str().cmp(other.as_str())
+        }
+    }
+
+    impl PartialOrd for NonEmptyString {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Hash for NonEmptyString {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.as_str().hash(state)
+        }
+    }
+
+    impl AsRef<str> for NonEmptyString {
+        fn as_ref(&self) -> &str {
+            self.as_str()
+        }
+    }
+
+    impl AsRef<[u8]> for NonEmptyString {
+        fn as_ref(&self) -> &[u8] {
+            self.as_str().as_bytes()
+        }
+    }
+
+    impl fmt::Debug for NonEmptyString {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(self.as_str(), f)
+        }
+    }
+
+    impl fmt::Display for NonEmptyString {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Display::fmt(self.as_str(), f)
+        }
+    }
+
+    impl Deref for NonEmptyString {
+        type Target = NonEmptyStr;
+
+        fn deref(&self) -> &Self::Target {
+            self.as_nonempty_str()
+        }
+    }
+
+    impl DerefMut for NonEmptyString {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            self.as_nonempty_mut_str()
+        }
+    }
+
+    impl FromStr for NonEmptyString {
+        type Err = EmptyError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match NonEmptyStr::from_str_checked(s) {
+                Some(s) => Ok(Self::from(s)),
+                None => Err(EmptyError),
+            }
+        }
+    }
+
+    impl<'a> TryFrom<&'a str> for &'a NonEmptyStr {
+        type Error = EmptyError;
+
+        fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+            NonEmptyStr::from_str_checked(s).ok_or(EmptyError)
+        }
+    }
+
+    impl TryFrom<String> for NonEmptyString {
+        type Error = EmptyStringError;
+
+        fn try_from(s: String) -> Result<Self, Self::Error> {
+            Self::from_string_checked(s).map_err(EmptyStringError::new)
+        }
+    }
+
+    impl From<&NonEmptyStr> for NonEmptyString {
+        fn from(s: &NonEmptyStr) -> Self {
+            Self {
+                inner: String::from(s.as_str()),
+            }
+        }
+    }
+
+    impl From<NonEmptyString> for String {
+        fn from(s: NonEmptyString) -> Self {
+            s.inner
+        }
+    }
+
+    impl Extend<char> for NonEmptyString {
+        fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
+
+    impl<'a> Extend<&'a str> for NonEmptyString {
+        fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
+};
+
+impl NonEmptyStr {
+    /// Converts a `&str` into a `&NonEmptyStr`.
+    /// Returns `None` if the passed string is empty.
+    ///
+    /// ```
+    /// # use oom::NonEmptyStr;
+    /// let s = NonEmptyStr::from_str_checked("hello").unwrap();
+    /// assert_eq!(s.first_char(), 'h');
+    /// assert!(NonEmptyStr::from_str_checked("").is_none());
+    /// ```
+    pub const fn from_str_checked(s: &str) -> Option<&Self> {
+        if s.is_empty() {
+            return None;
+        }
+
+        Some(unsafe { Self::from_str_unchecked(s) })
+    }
+
+    /// Converts a `&mut str` into a `&mut NonEmptyStr`.
+    /// Returns `None` if the passed string is empty.
+    pub fn from_mut_str_checked(s: &mut str) -> Option<&mut Self> {
+        if s.is_empty() {
+            return None;
+        }
+
+        Some(unsafe { Self::from_mut_str_unchecked(s) })
+    }
+
+    /// Converts a `&str` into a `&NonEmptyStr` without checking
+    /// that it is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The passed string must not be empty.
+    pub const unsafe fn from_str_unchecked(s: &str) -> &Self {
+        debug_assert!(!s.is_empty());
+        // SAFETY: `NonEmptyStr` is `repr(transparent)` over `str`.
+        &*(s as *const str as *const Self)
+    }
+
+    /// Converts a `&mut str` into a `&mut NonEmptyStr` without checking
+    /// that it is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The passed string must not be empty.
+    pub unsafe fn from_mut_str_unchecked(s: &mut str) -> &mut Self {
+        debug_assert!(!s.is_empty());
+        // SAFETY: `NonEmptyStr` is `repr(transparent)` over `str`.
+        &mut *(s as *mut str as *mut Self)
+    }
+
+    /// Returns a `&str` containing the entire `NonEmptyStr`.
+    pub const fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    /// Returns a `&mut str` containing the entire `NonEmptyStr`.
+    pub fn as_mut_str(&mut self) -> &mut str {
+        &mut self.inner
+    }
+
+    /// Converts the string slice into a non-empty byte slice.
+    ///
+    /// ```
+    /// # use oom::NonEmptyStr;
+    /// let s = NonEmptyStr::from_str_checked("bors").unwrap();
+    /// assert_eq!(s.as_bytes().first(), &b'b');
+    /// ```
+    pub const fn as_bytes(&self) -> &NonEmptySlice<u8> {
+        unsafe { NonEmptySlice::from_slice_unchecked(self.inner.as_bytes()) }
+    }
+
+    /// Returns the length of the string, in bytes.
+    pub const fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.inner.len()) }
+    }
+
+    /// Always returns `false` because the string is non-empty.
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns an iterator over the `char`s of the string.
+    pub fn chars(&self) -> str::Chars<'_> {
+        self.inner.chars()
+    }
+
+    /// Returns the first character of the string.
+    ///
+    /// ```
+    /// # use oom::NonEmptyStr;
+    /// let s = NonEmptyStr::from_str_checked("ñandú").unwrap();
+    /// assert_eq!(s.first_char(), 'ñ');
+    /// ```
+    pub fn first_char(&self) -> char {
+        match self.inner.chars().next() {
+            Some(c) => c,
+            None => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns the last character of the string.
+    ///
+    /// ```
+    /// # use oom::NonEmptyStr;
+    /// let s = NonEmptyStr::from_str_checked("ñandú").unwrap();
+    /// assert_eq!(s.last_char(), 'ú');
+    /// ```
+    pub fn last_char(&self) -> char {
+        match self.inner.chars().next_back() {
+            Some(c) => c,
+            None => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns the first character and the rest of the string.
+    ///
+    /// ```
+    /// # use oom::NonEmptyStr;
+    /// let s = NonEmptyStr::from_str_checked("rust").unwrap();
+    /// assert_eq!(s.split_first_char(), ('r', "ust"));
+    /// ```
+    pub fn split_first_char(&self) -> (char, &str) {
+        let first = self.first_char();
+        (first, &self.inner[first.len_utf8()..])
+    }
+
+    /// Returns the last character and the rest of the string.
+    pub fn split_last_char(&self) -> (char, &str) {
+        let last = self.last_char();
+        (last, &self.inner[..self.inner.len() - last.len_utf8()])
+    }
+}
+
+impl NonEmptyString {
+    /// Constructs a new `NonEmptyString` containing a single character.
+    pub fn new(first: char) -> Self {
+        let mut inner = String::with_capacity(first.len_utf8());
+        inner.push(first);
+        Self { inner }
+    }
+
+    /// Converts a `String` into a `NonEmptyString`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if passed `String` is empty.
+    pub fn from_string(s: String) -> Self {
+        match Self::from_string_checked(s) {
+            Ok(s) => s,
+            Err(_) => panic!("string shouldn't be empty"),
+        }
+    }
+
+    /// Converts a `String` into a `NonEmptyString`.
+    /// Returns passed `String` if it is empty.
+    pub fn from_string_checked(s: String) -> Result<Self, String> {
+        if s.is_empty() {
+            return Err(s);
+        }
+        Ok(Self { inner: s })
+    }
+
+    /// Converts a `String` into a `NonEmptyString` without checking
+    /// that it is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The passed `String` must not be empty.
+    pub unsafe fn from_string_unchecked(s: String) -> Self {
+        debug_assert!(!s.is_empty());
+        Self { inner: s }
+    }
+
+    /// Returns a non-empty string slice from this string.
+    pub fn as_nonempty_str(&self) -> &NonEmptyStr {
+        unsafe { NonEmptyStr::from_str_unchecked(&self.inner) }
+    }
+
+    /// Returns a non-empty mutable string slice from this string.
+    pub fn as_nonempty_mut_str(&mut self) -> &mut NonEmptyStr {
+        unsafe { NonEmptyStr::from_mut_str_unchecked(&mut self.inner) }
+    }
+
+    /// Returns this string's capacity, in bytes.
+    pub fn capacity(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.inner.capacity()) }
+    }
+
+    /// Converts `self` into a `String` without clones or allocations.
+    pub fn into_string(self) -> String {
+        self.inner
+    }
+
+    /// Appends the given `char` to the end of this string.
+    pub fn push(&mut self, ch: char) {
+        self.inner.push(ch);
+    }
+
+    /// Appends a given string slice onto the end of this string.
+    ///
+    /// ```
+    /// # use oom::NonEmptyString;
+    /// let mut s = NonEmptyString::new('f');
+    /// s.push_str("oo");
+    /// assert_eq!(s.as_str(), "foo");
+    /// ```
+    pub fn push_str(&mut self, string: &str) {
+        self.inner.push_str(string);
+    }
+
+    /// Removes the last character from the string and returns it,
+    /// or `None` if it is the only character left.
+    pub fn pop(&mut self) -> Option<char> {
+        let (last, rest) = self.split_last_char();
+        if rest.is_empty() {
+            return None;
+        }
+        self.inner.pop();
+        Some(last)
+    }
+}
@@ -1,12 +1,45 @@
-extern crate alloc;
-
-use alloc::vec::Vec;
+use alloc::borrow::{Cow, ToOwned};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::vec::{self, Vec};
+use core::borrow::{Borrow, BorrowMut};
 use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::hint::unreachable_unchecked;
 use core::mem::size_of;
 use core::num::NonZeroUsize;
+use core::ops::{Add, AddAssign, Deref, DerefMut, Index, IndexMut};
+use core::slice::{self, SliceIndex};
+
+use crate::{EmptyError, EmptyVecError, NonEmptySlice, RemoveError};
 
-use crate::{NonEmptyMutSlice, NonEmptySlice};
+/// A non-empty clone-on-write slice, counterpart of `Cow<'a, [T]>`.
+///
+/// It either borrows a [`NonEmptySlice`] or owns a [`NonEmptyVec`],
+/// and dereferences to `NonEmptySlice<T>` in both cases.
+///
+/// ```
+/// # use oom::{nonempty, NonEmptyCow, NonEmptySlice};
+/// fn abs_all(s: &NonEmptySlice<i32>) -> NonEmptyCow<'_, i32> {
+///     let mut cow = NonEmptyCow::from(s);
+///     for i in 0..s.len().get() {
+///         if cow[i] < 0 {
+///             cow.to_mut()[i] = -cow[i];
+///         }
+///     }
+///     cow
+/// }
+///
+/// let v = nonempty![1, 2];
+/// assert!(matches!(abs_all(&v), NonEmptyCow::Borrowed(_)));
+/// let v = nonempty![-1, 2];
+/// assert_eq!(abs_all(&v).into_owned(), [1, 2]);
+/// ```
+pub type NonEmptyCow<'a, T> = Cow<'a, NonEmptySlice<T>>;
 
 /// A non-empty vector type, counterpart of `Vec<T>`.
 pub struct NonEmptyVec<T: Sized> {
@@ -26,7 +59,9 @@ const _SIZE: () = {
 const _BUILTIN_TRAITS: () = {
     impl<T: Clone> Clone for NonEmptyVec<T> {
         fn clone(&self) -> Self {
-            Self::from_vec(self.to_vec())
+            Self {
+                inner: self.inner.clone(),
+            }
         }
     }
 
@@ -55,9 +90,472 @@ const _BUILTIN_TRAITS: () = {
             self.as_slice()
         }
     }
+
+    impl<T> AsMut<[T]> for NonEmptyVec<T> {
+        fn as_mut(&mut self) -> &mut [T] {
+            self.as_mut_slice()
+        }
+    }
+
+    impl<T> AsRef<NonEmptySlice<T>> for NonEmptyVec<T> {
+        fn as_ref(&self) -> &NonEmptySlice<T> {
+            self.as_nonempty_slice()
+        }
+    }
+
+    impl<T> AsMut<NonEmptySlice<T>> for NonEmptyVec<T> {
+        fn as_mut(&mut self) -> &mut NonEmptySlice<T> {
+            self.as_nonempty_mut_slice()
+        }
+    }
+
+    impl<T> Borrow<[T]> for NonEmptyVec<T> {
+        fn borrow(&self) -> &[T] {
+            self.as_slice()
+        }
+    }
+
+    impl<T> BorrowMut<[T]> for NonEmptyVec<T> {
+        fn borrow_mut(&mut self) -> &mut [T] {
+            self.as_mut_slice()
+        }
+    }
+
+    impl<T> Borrow<NonEmptySlice<T>> for NonEmptyVec<T> {
+        fn borrow(&self) -> &NonEmptySlice<T> {
+            self.as_nonempty_slice()
+        }
+    }
+
+    impl<T> BorrowMut<NonEmptySlice<T>> for NonEmptyVec<T> {
+        fn borrow_mut(&mut self) -> &mut NonEmptySlice<T> {
+            self.as_nonempty_mut_slice()
+        }
+    }
+
+    impl<T: Clone> ToOwned for NonEmptySlice<T> {
+        type Owned = NonEmptyVec<T>;
+
+        fn to_owned(&self) -> NonEmptyVec<T> {
+            self.to_nonempty_vec()
+        }
+    }
+
+    impl<'a, T: Clone> From<&'a NonEmptySlice<T>> for NonEmptyCow<'a, T> {
+        fn from(slice: &'a NonEmptySlice<T>) -> Self {
+            Cow::Borrowed(slice)
+        }
+    }
+
+    impl<'a, T: Clone> From<&'a NonEmptyVec<T>> for NonEmptyCow<'a, T> {
+        fn from(vec: &'a NonEmptyVec<T>) -> Self {
+            Cow::Borrowed(vec.as_nonempty_slice())
+        }
+    }
+
+    impl<'a, T: Clone> From<NonEmptyVec<T>> for NonEmptyCow<'a, T> {
+        fn from(vec: NonEmptyVec<T>) -> Self {
+            Cow::Owned(vec)
+        }
+    }
+
+    impl<'a, T: Clone> From<NonEmptyCow<'a, T>> for NonEmptyVec<T> {
+        fn from(cow: NonEmptyCow<'a, T>) -> Self {
+            cow.into_owned()
+        }
+    }
+
+    impl<T: Hash> Hash for NonEmptyVec<T> {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.as_slice().hash(state)
+        }
+    }
+
+    impl<T: fmt::Debug> fmt::Debug for NonEmptyVec<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.as_slice().fmt(f)
+        }
+    }
+
+    impl<T, I: SliceIndex<[T]>> Index<I> for NonEmptyVec<T> {
+        type Output = I::Output;
+
+        fn index(&self, index: I) -> &Self::Output {
+            self.as_slice().index(index)
+        }
+    }
+
+    impl<T, I: SliceIndex<[T]>> IndexMut<I> for NonEmptyVec<T> {
+        fn index_mut(&mut self, index: I) -> &mut Self::Output {
+            self.as_mut_slice().index_mut(index)
+        }
+    }
+
+    impl<T: PartialEq<U>, U> PartialEq<[U]> for NonEmptyVec<T> {
+        fn eq(&self, other: &[U]) -> bool {
+            self.as_slice() == other
+        }
+    }
+
+    impl<T: PartialEq<U>, U> PartialEq<&[U]> for NonEmptyVec<T> {
+        fn eq(&self, other: &&[U]) -> bool {
+            self.as_slice() == *other
+        }
+    }
+
+    impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for NonEmptyVec<T> {
+        fn eq(&self, other: &[U; N]) -> bool {
+            self.as_slice() == other
+        }
+    }
+
+    impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for NonEmptyVec<T> {
+        fn eq(&self, other: &Vec<U>) -> bool {
+            self.as_slice() == other.as_slice()
+        }
+    }
+
+    impl<T: PartialEq<U>, U> PartialEq<NonEmptyVec<U>> for Vec<T> {
+        fn eq(&self, other: &NonEmptyVec<U>) -> bool {
+            self.as_slice() == other.as_slice()
+        }
+    }
+
+    impl<T: PartialEq<U>, U> PartialEq<NonEmptySlice<U>> for NonEmptyVec<T> {
+        fn eq(&self, other: &NonEmptySlice<U>) -> bool {
+            self.as_slice() == other.as_slice()
+        }
+    }
+
+    impl<T: PartialEq<U>, U> PartialEq<NonEmptyVec<U>> for NonEmptySlice<T> {
+        fn eq(&self, other: &NonEmptyVec<U>) -> bool {
+            self.as_slice() == other.as_slice()
+        }
+    }
+
+    impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for NonEmptySlice<T> {
+        fn eq(&self, other: &Vec<U>) -> bool {
+            self.as_slice() == other.as_slice()
+        }
+    }
+
+    impl<T: PartialEq<U>, U> PartialEq<NonEmptySlice<U>> for Vec<T> {
+        fn eq(&self, other: &NonEmptySlice<U>) -> bool {
+            self.as_slice() == other.as_slice()
+        }
+    }
+
+    impl<T: Clone> Add<&[T]> for NonEmptyVec<T> {
+        type Output = Self;
+
+        fn add(mut self, other: &[T]) -> Self {
+            self.extend_from_slice(other);
+            self
+        }
+    }
+
+    impl<T> Add<Vec<T>> for NonEmptyVec<T> {
+        type Output = Self;
+
+        fn add(mut self, mut other: Vec<T>) -> Self {
+            self.append(&mut other);
+            self
+        }
+    }
+
+    impl<T> Add for NonEmptyVec<T> {
+        type Output = Self;
+
+        fn add(self, other: Self) -> Self {
+            self + other.inner
+        }
+    }
+
+    impl<T> Add<NonEmptyVec<T>> for Vec<T> {
+        type Output = NonEmptyVec<T>;
+
+        fn add(mut self, mut other: NonEmptyVec<T>) -> NonEmptyVec<T> {
+            self.append(&mut other.inner);
+            NonEmptyVec { inner: self }
+        }
+    }
+
+    impl<T: Clone> AddAssign<&[T]> for NonEmptyVec<T> {
+        fn add_assign(&mut self, other: &[T]) {
+            self.extend_from_slice(other);
+        }
+    }
+
+    impl<T> AddAssign<Vec<T>> for NonEmptyVec<T> {
+        fn add_assign(&mut self, mut other: Vec<T>) {
+            self.append(&mut other);
+        }
+    }
+
+    impl<T> From<NonEmptyVec<T>> for Vec<T> {
+        fn from(vec: NonEmptyVec<T>) -> Self {
+            vec.into_vec()
+        }
+    }
+
+    impl<T> From<NonEmptyVec<T>> for Box<[T]> {
+        fn from(vec: NonEmptyVec<T>) -> Self {
+            vec.inner.into_boxed_slice()
+        }
+    }
+
+    impl<T> Deref for NonEmptyVec<T> {
+        type Target = NonEmptySlice<T>;
+
+        fn deref(&self) -> &Self::Target {
+            self.as_nonempty_slice()
+        }
+    }
+
+    impl<T> DerefMut for NonEmptyVec<T> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            self.as_nonempty_mut_slice()
+        }
+    }
+
+    impl<T> From<NonEmptyVec<T>> for Box<NonEmptySlice<T>> {
+        fn from(vec: NonEmptyVec<T>) -> Self {
+            vec.into_boxed_slice()
+        }
+    }
+
+    impl<T> From<Box<NonEmptySlice<T>>> for NonEmptyVec<T> {
+        fn from(slice: Box<NonEmptySlice<T>>) -> Self {
+            Self {
+                inner: Box::<[T]>::from(slice).into_vec(),
+            }
+        }
+    }
+
+    impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
+        type Error = EmptyVecError<T>;
+
+        fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+            Self::from_vec_checked(vec).map_err(EmptyVecError::new)
+        }
+    }
+
+    impl<T: Clone> TryFrom<&[T]> for NonEmptyVec<T> {
+        type Error = EmptyError;
+
+        fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+            match NonEmptySlice::from_slice_checked(slice) {
+                Some(slice) => Ok(Self::from(slice)),
+                None => Err(EmptyError),
+            }
+        }
+    }
+
+    impl<T: Clone> TryFrom<&mut [T]> for NonEmptyVec<T> {
+        type Error = EmptyError;
+
+        fn try_from(slice: &mut [T]) -> Result<Self, Self::Error> {
+            Self::try_from(&*slice)
+        }
+    }
+
+    impl<T: Clone> From<&NonEmptySlice<T>> for NonEmptyVec<T> {
+        fn from(slice: &NonEmptySlice<T>) -> Self {
+            slice.to_nonempty_vec()
+        }
+    }
+
+    impl<T: Clone> From<&NonEmptySlice<T>> for Box<NonEmptySlice<T>> {
+        fn from(slice: &NonEmptySlice<T>) -> Self {
+            let slice = Box::<[T]>::from(slice.as_slice());
+            unsafe {
+                Box::from_raw(Box::into_raw(slice) as *mut NonEmptySlice<T>)
+            }
+        }
+    }
+
+    impl<T> From<NonEmptyVec<T>> for Rc<NonEmptySlice<T>> {
+        fn from(vec: NonEmptyVec<T>) -> Self {
+            let slice = Rc::<[T]>::from(vec.inner);
+            unsafe {
+                Rc::from_raw(Rc::into_raw(slice) as *const NonEmptySlice<T>)
+            }
+        }
+    }
+
+    impl<T: Clone> From<&NonEmptySlice<T>> for Rc<NonEmptySlice<T>> {
+        fn from(slice: &NonEmptySlice<T>) -> Self {
+            let slice = Rc::<[T]>::from(slice.as_slice());
+            unsafe {
+                Rc::from_raw(Rc::into_raw(slice) as *const NonEmptySlice<T>)
+            }
+        }
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    impl<T> From<NonEmptyVec<T>> for Arc<NonEmptySlice<T>> {
+        fn from(vec: NonEmptyVec<T>) -> Self {
+            let slice = Arc::<[T]>::from(vec.inner);
+            unsafe {
+                Arc::from_raw(Arc::into_raw(slice) as *const NonEmptySlice<T>)
+            }
+        }
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    impl<T: Clone> From<&NonEmptySlice<T>> for Arc<NonEmptySlice<T>> {
+        fn from(slice: &NonEmptySlice<T>) -> Self {
+            let slice = Arc::<[T]>::from(slice.as_slice());
+            unsafe {
+                Arc::from_raw(Arc::into_raw(slice) as *const NonEmptySlice<T>)
+            }
+        }
+    }
+
+    impl<T: Clone> Clone for Box<NonEmptySlice<T>> {
+        fn clone(&self) -> Self {
+            Self::from(&**self)
+        }
+    }
+
+    impl<T> From<Box<NonEmptySlice<T>>> for Box<[T]> {
+        fn from(slice: Box<NonEmptySlice<T>>) -> Self {
+            unsafe { Box::from_raw(Box::into_raw(slice) as *mut [T]) }
+        }
+    }
+
+    impl<T> TryFrom<Box<[T]>> for Box<NonEmptySlice<T>> {
+        type Error = EmptyError;
+
+        fn try_from(slice: Box<[T]>) -> Result<Self, Self::Error> {
+            if slice.is_empty() {
+                return Err(EmptyError);
+            }
+            unsafe {
+                Ok(Box::from_raw(Box::into_raw(slice) as *mut NonEmptySlice<T>))
+            }
+        }
+    }
+
+    impl<T: Clone> From<Rc<NonEmptySlice<T>>> for NonEmptyVec<T> {
+        fn from(slice: Rc<NonEmptySlice<T>>) -> Self {
+            Self::from(&*slice)
+        }
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    impl<T: Clone> From<Arc<NonEmptySlice<T>>> for NonEmptyVec<T> {
+        fn from(slice: Arc<NonEmptySlice<T>>) -> Self {
+            Self::from(&*slice)
+        }
+    }
+
+    impl<T> IntoIterator for NonEmptyVec<T> {
+        type Item = T;
+        type IntoIter = vec::IntoIter<T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.inner.into_iter()
+        }
+    }
+
+    impl<'a, T> IntoIterator for &'a NonEmptyVec<T> {
+        type Item = &'a T;
+        type IntoIter = slice::Iter<'a, T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<'a, T> IntoIterator for &'a mut NonEmptyVec<T> {
+        type Item = &'a mut T;
+        type IntoIter = slice::IterMut<'a, T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+
+    #[cfg(feature = "iter")]
+    impl<T> crate::FromNonEmptyIterator<T> for NonEmptyVec<T> {
+        fn from_nonempty_iter<I>(iter: I) -> Self
+        where
+            I: crate::NonEmptyIterator<Item = T>,
+        {
+            Self {
+                inner: iter.into_iter().collect(),
+            }
+        }
+    }
+
+    impl<T> Extend<T> for NonEmptyVec<T> {
+        fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
+
+    impl<'a, T: Copy + 'a> Extend<&'a T> for NonEmptyVec<T> {
+        fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
 };
 
 impl<T: Sized> NonEmptyVec<T> {
+    /// Constructs a new `NonEmptyVec` containing a single element.
+    ///
+    /// ```
+    /// # use oom::NonEmptyVec;
+    /// let v = NonEmptyVec::new(42);
+    /// assert_eq!(v.as_slice(), &[42]);
+    /// ```
+    pub fn new(first: T) -> Self {
+        Self {
+            inner: alloc::vec![first],
+        }
+    }
+
+    /// Constructs a new `NonEmptyVec` from the first element
+    /// and the rest of the elements.
+    ///
+    /// ```
+    /// # use oom::NonEmptyVec;
+    /// let v = NonEmptyVec::from_head_tail(1, vec![2, 3]);
+    /// assert_eq!(v.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn from_head_tail(head: T, tail: Vec<T>) -> Self {
+        let mut inner = tail;
+        inner.insert(0, head);
+        Self { inner }
+    }
+
+    /// Constructs a new `NonEmptyVec` containing `first`, with at least
+    /// the specified capacity.
+    pub fn with_capacity(first: T, capacity: NonZeroUsize) -> Self {
+        let mut inner = Vec::with_capacity(capacity.get());
+        inner.push(first);
+        Self { inner }
+    }
+
+    /// Constructs a new `NonEmptyVec` with `n` clones of `elem`.
+    ///
+    /// ```
+    /// # use oom::NonEmptyVec;
+    /// # use core::num::NonZeroUsize;
+    /// let v = NonEmptyVec::from_elem(0, NonZeroUsize::new(3).unwrap());
+    /// assert_eq!(v.as_slice(), &[0, 0, 0]);
+    /// ```
+    pub fn from_elem(elem: T, n: NonZeroUsize) -> Self
+    where
+        T: Clone,
+    {
+        let mut inner = Vec::with_capacity(n.get());
+        inner.resize(n.get() - 1, elem.clone());
+        inner.push(elem);
+        Self { inner }
+    }
+
     /// Converts a `Vec<T>` into a `NonEmptyVec`.
     ///
     /// # Panics
@@ -79,46 +577,49 @@ impl<T: Sized> NonEmptyVec<T> {
         Ok(Self { inner: vec })
     }
 
-    /// Returns a raw pointer to the vector's buffer.
-    pub fn as_ptr(&self) -> *const T {
-        self.inner.as_ptr()
-    }
-
-    /// Returns an unsafe mutable pointer to the vector's buffer.
-    pub fn as_mut_ptr(&mut self) -> *mut T {
-        self.inner.as_mut_ptr()
+    /// Converts a `Vec<T>` into a `NonEmptyVec` without checking
+    /// that it is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The passed `Vec` must not be empty.
+    pub unsafe fn from_vec_unchecked(vec: Vec<T>) -> Self {
+        debug_assert!(!vec.is_empty());
+        Self { inner: vec }
     }
 
     /// Returns a non-empty slice from this vec.
-    pub fn as_nonempty_slice(&self) -> NonEmptySlice<'_, T> {
-        NonEmptySlice { inner: &self.inner }
+    pub fn as_nonempty_slice(&self) -> &NonEmptySlice<T> {
+        unsafe { NonEmptySlice::from_slice_unchecked(&self.inner) }
     }
 
     /// Returns a non-empty mutable slice from this vec.
-    pub fn as_nonempty_mut_slice(&mut self) -> NonEmptyMutSlice<'_, T> {
-        NonEmptyMutSlice {
-            inner: &mut self.inner,
-        }
-    }
-
-    /// Extracts a slice containing the entire vector.
-    pub fn as_slice(&self) -> &[T] {
-        self.inner.as_slice()
-    }
-
-    /// Extracts a mutable slice of the entire vector.
-    pub fn as_mut_slice(&mut self) -> &mut [T] {
-        self.inner.as_mut_slice()
+    pub fn as_nonempty_mut_slice(&mut self) -> &mut NonEmptySlice<T> {
+        unsafe { NonEmptySlice::from_mut_slice_unchecked(&mut self.inner) }
     }
 
-    /// Returns the number of elements in the vector.
-    pub fn len(&self) -> NonZeroUsize {
-        unsafe { NonZeroUsize::new_unchecked(self.inner.len()) }
+    /// Creates a non-empty iterator which moves all values out of the vector.
+    #[cfg(feature = "iter")]
+    pub fn into_nonempty_iter(self) -> crate::iter::IntoIter<T> {
+        crate::iter::IntoIter {
+            inner: self.inner.into_iter(),
+        }
     }
 
-    /// Always returns `false` because the vector is non-empty.
-    pub fn is_empty(&self) -> bool {
-        false
+    /// Splits the vector into its first element and the rest.
+    ///
+    /// This is `O(n)`, because the rest is shifted to the front
+    /// to keep the allocation.
+    ///
+    /// ```
+    /// # use oom::nonempty;
+    /// let (head, tail) = nonempty![1, 2, 3].into_head_tail();
+    /// assert_eq!((head, tail), (1, vec![2, 3]));
+    /// ```
+    pub fn into_head_tail(self) -> (T, Vec<T>) {
+        let mut tail = self.inner;
+        let head = tail.remove(0);
+        (head, tail)
     }
 
     /// Returns the number of elements the vector can hold without reallocating.
@@ -126,6 +627,20 @@ impl<T: Sized> NonEmptyVec<T> {
         unsafe { NonZeroUsize::new_unchecked(self.inner.capacity()) }
     }
 
+    /// Converts the vector into `Box<NonEmptySlice<T>>`,
+    /// dropping any excess capacity.
+    ///
+    /// ```
+    /// # use oom::NonEmptyVec;
+    /// let v = NonEmptyVec::from_vec(vec![1, 2, 3]);
+    /// let b = v.into_boxed_slice();
+    /// assert_eq!(b.last(), &3);
+    /// ```
+    pub fn into_boxed_slice(self) -> Box<NonEmptySlice<T>> {
+        let slice = self.inner.into_boxed_slice();
+        unsafe { Box::from_raw(Box::into_raw(slice) as *mut NonEmptySlice<T>) }
+    }
+
     /// Converts `self` into a vector without clones or allocations.
     pub fn into_vec(self) -> Vec<T> {
         self.inner
@@ -139,67 +654,364 @@ impl<T: Sized> NonEmptyVec<T> {
         self.as_slice().to_vec()
     }
 
-    /// Returns the first element of the slice.
-    pub fn first(&self) -> &T {
-        match self.as_slice() {
-            [first, ..] => first,
-            [] => unsafe { unreachable_unchecked() },
+    /// Appends an element to the back of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    pub fn push(&mut self, value: T) {
+        self.inner.push(value);
+    }
+
+    /// Removes the last element from the vector and returns it,
+    /// or `None` if it is the only element left.
+    ///
+    /// ```
+    /// # use oom::NonEmptyVec;
+    /// let mut v = NonEmptyVec::from_vec(vec![1, 2]);
+    /// assert_eq!(v.pop(), Some(2));
+    /// assert_eq!(v.pop(), None);
+    /// assert_eq!(v.as_slice(), &[1]);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.inner.len() == 1 {
+            return None;
         }
+        self.inner.pop()
     }
 
-    /// Returns a mutable pointer to the first element of the slice.
-    pub fn first_mut(&mut self) -> &mut T {
-        match self.as_mut_slice() {
-            [first, ..] => first,
-            [] => unsafe { unreachable_unchecked() },
+    /// Inserts an element at position `index` within the vector,
+    /// shifting all elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.inner.insert(index, element);
+    }
+
+    /// Removes and returns the element at position `index` within the vector,
+    /// shifting all elements after it to the left.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RemoveError::OutOfBounds`] if `index` is out of bounds,
+    /// and [`RemoveError::LastElement`] if it is the only element left.
+    ///
+    /// ```
+    /// # use oom::{NonEmptyVec, RemoveError};
+    /// let mut v = NonEmptyVec::from_vec(vec![1, 2, 3]);
+    /// assert_eq!(v.try_remove(1), Ok(2));
+    /// assert!(matches!(
+    ///     v.try_remove(2),
+    ///     Err(RemoveError::OutOfBounds { index: 2, .. })
+    /// ));
+    /// assert_eq!(v.try_remove(0), Ok(1));
+    /// assert_eq!(v.try_remove(0), Err(RemoveError::LastElement));
+    /// assert_eq!(v.as_slice(), &[3]);
+    /// ```
+    pub fn try_remove(&mut self, index: usize) -> Result<T, RemoveError> {
+        let len = self.len();
+        if index >= len.get() {
+            return Err(RemoveError::OutOfBounds { index, len });
+        }
+        if len.get() == 1 {
+            return Err(RemoveError::LastElement);
         }
+        Ok(self.inner.remove(index))
     }
 
-    /// Returns the last element of the slice.
-    pub fn last(&self) -> &T {
-        match self.as_slice() {
-            [.., last] => last,
-            [] => unsafe { unreachable_unchecked() },
+    /// Removes an element from the vector and returns it.
+    /// The removed element is replaced by the last element of the vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RemoveError::OutOfBounds`] if `index` is out of bounds,
+    /// and [`RemoveError::LastElement`] if it is the only element left.
+    ///
+    /// ```
+    /// # use oom::{NonEmptyVec, RemoveError};
+    /// let mut v = NonEmptyVec::from_vec(vec![1, 2, 3]);
+    /// assert_eq!(v.swap_remove(0), Ok(1));
+    /// assert_eq!(v.as_slice(), &[3, 2]);
+    /// assert!(matches!(
+    ///     v.swap_remove(2),
+    ///     Err(RemoveError::OutOfBounds { index: 2, .. })
+    /// ));
+    /// v.swap_remove(0).unwrap();
+    /// assert_eq!(v.swap_remove(0), Err(RemoveError::LastElement));
+    /// ```
+    pub fn swap_remove(&mut self, index: usize) -> Result<T, RemoveError> {
+        let len = self.len();
+        if index >= len.get() {
+            return Err(RemoveError::OutOfBounds { index, len });
+        }
+        if len.get() == 1 {
+            return Err(RemoveError::LastElement);
+        }
+        Ok(self.inner.swap_remove(index))
+    }
+
+    /// Shortens the vector, keeping the first `len` elements
+    /// and dropping the rest.
+    ///
+    /// If `len` is greater than the vector's current length,
+    /// this has no effect.
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        self.inner.truncate(len.get());
+    }
+
+    /// Removes consecutive repeated elements in the vector.
+    ///
+    /// The first element is always kept, so the vector stays non-empty.
+    ///
+    /// ```
+    /// # use oom::nonempty;
+    /// let mut v = nonempty![1, 1, 2, 2, 2, 1];
+    /// v.dedup();
+    /// assert_eq!(v, [1, 2, 1]);
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.inner.dedup();
+    }
+
+    /// Removes all but the first of consecutive elements in the vector
+    /// satisfying a given equality relation.
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        self.inner.dedup_by(same_bucket);
+    }
+
+    /// Removes all but the first of consecutive elements in the vector
+    /// that resolve to the same key.
+    pub fn dedup_by_key<K, F>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.inner.dedup_by_key(key);
+    }
+
+    /// Clones and appends all elements in a slice to the vector.
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.inner.extend_from_slice(other);
+    }
+
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    pub fn append(&mut self, other: &mut Vec<T>) {
+        self.inner.append(other);
+    }
+
+    /// Returns a vector of the same length as `self`, with `f` applied
+    /// to each element in order.
+    ///
+    /// The allocation is reused when `T` and `U` have the same layout.
+    ///
+    /// ```
+    /// # use oom::nonempty;
+    /// let v = nonempty![1, 2, 3].map(|x| x * 2);
+    /// assert_eq!(v, [2, 4, 6]);
+    /// ```
+    pub fn map<U, F>(self, f: F) -> NonEmptyVec<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        NonEmptyVec {
+            inner: self.inner.into_iter().map(f).collect(),
+        }
+    }
+
+    /// Returns a vector of the same length as `self`, with `f` applied
+    /// to a reference of each element in order.
+    pub fn map_ref<U, F>(&self, f: F) -> NonEmptyVec<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        NonEmptyVec {
+            inner: self.inner.iter().map(f).collect(),
+        }
+    }
+
+    /// Applies the fallible function `f` to each element in order,
+    /// returning the first error.
+    ///
+    /// See [`try_map_opt`](Self::try_map_opt) for `Option`-returning
+    /// functions.
+    ///
+    /// ```
+    /// # use oom::nonempty;
+    /// let v = nonempty!["1", "2"].try_map(str::parse::<u8>);
+    /// assert_eq!(v, Ok(nonempty![1, 2]));
+    /// let v = nonempty!["1", "x"].try_map(str::parse::<u8>);
+    /// assert!(v.is_err());
+    /// ```
+    pub fn try_map<U, E, F>(self, f: F) -> Result<NonEmptyVec<U>, E>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        let inner = self.inner.into_iter().map(f).collect::<Result<_, _>>()?;
+        Ok(NonEmptyVec { inner })
+    }
+
+    /// Applies the partial function `f` to each element in order,
+    /// returning `None` as soon as `f` does.
+    ///
+    /// ```
+    /// # use oom::nonempty;
+    /// let v = nonempty![4u8, 2].try_map_opt(|x| x.checked_sub(1));
+    /// assert_eq!(v, Some(nonempty![3, 1]));
+    /// let v = nonempty![4u8, 0].try_map_opt(|x| x.checked_sub(1));
+    /// assert_eq!(v, None);
+    /// ```
+    pub fn try_map_opt<U, F>(self, f: F) -> Option<NonEmptyVec<U>>
+    where
+        F: FnMut(T) -> Option<U>,
+    {
+        let inner = self.inner.into_iter().map(f).collect::<Option<_>>()?;
+        Some(NonEmptyVec { inner })
+    }
+
+    /// Zips two vectors into a vector of pairs.
+    ///
+    /// The result is as long as the shorter one, so it is non-empty.
+    pub fn zip<U>(self, other: NonEmptyVec<U>) -> NonEmptyVec<(T, U)> {
+        NonEmptyVec {
+            inner: self.inner.into_iter().zip(other.inner).collect(),
         }
     }
 
-    /// Returns the last element of the slice.
-    pub fn last_mut(&mut self) -> &mut T {
-        match self.as_mut_slice() {
-            [.., last] => last,
-            [] => unsafe { unreachable_unchecked() },
+    /// Pairs each element with its index.
+    pub fn enumerate(self) -> NonEmptyVec<(usize, T)> {
+        NonEmptyVec {
+            inner: self.inner.into_iter().enumerate().collect(),
         }
     }
+}
+
+impl<A, B> NonEmptyVec<(A, B)> {
+    /// Splits a vector of pairs into a pair of vectors.
+    ///
+    /// ```
+    /// # use oom::nonempty;
+    /// let (a, b) = nonempty![(1, 'a'), (2, 'b')].unzip();
+    /// assert_eq!((a, b), (nonempty![1, 2], nonempty!['a', 'b']));
+    /// ```
+    pub fn unzip(self) -> (NonEmptyVec<A>, NonEmptyVec<B>) {
+        let (a, b) = self.inner.into_iter().unzip();
+        (NonEmptyVec { inner: a }, NonEmptyVec { inner: b })
+    }
+}
 
-    /// Returns the first and all the rest of the elements of the slice.
-    pub fn split_first(&self) -> (&T, &[T]) {
-        match self.as_slice() {
-            [first, rest @ ..] => (first, rest),
-            [] => unsafe { unreachable_unchecked() },
+impl<T> NonEmptyVec<NonEmptyVec<T>> {
+    /// Flattens a vector of vectors into a single vector.
+    ///
+    /// ```
+    /// # use oom::nonempty;
+    /// let v = nonempty![nonempty![1, 2], nonempty![3]];
+    /// assert_eq!(v.flatten(), [1, 2, 3]);
+    /// ```
+    pub fn flatten(self) -> NonEmptyVec<T> {
+        NonEmptyVec {
+            inner: self.inner.into_iter().flat_map(|v| v.inner).collect(),
         }
     }
+}
 
-    /// Returns the first and all the rest of the elements of the slice.
-    pub fn split_first_mut(&mut self) -> (&mut T, &mut [T]) {
-        match self.as_mut_slice() {
-            [first, rest @ ..] => (first, rest),
-            [] => unsafe { unreachable_unchecked() },
+impl<V> NonEmptySlice<V> {
+    /// Flattens a slice of non-empty slices into a single vector.
+    ///
+    /// ```
+    /// # use oom::{nonempty, NonEmptySlice};
+    /// let v = [nonempty![1, 2], nonempty![3]];
+    /// let s = NonEmptySlice::from_slice(&v);
+    /// assert_eq!(s.concat(), [1, 2, 3]);
+    /// ```
+    pub fn concat<T: Clone>(&self) -> NonEmptyVec<T>
+    where
+        V: Borrow<NonEmptySlice<T>>,
+    {
+        let mut vec = Vec::new();
+        for v in self {
+            vec.extend_from_slice(v.borrow().as_slice());
         }
+        NonEmptyVec { inner: vec }
     }
 
-    /// Returns the last and all the rest of the elements of the slice.
-    pub fn split_last(&self) -> (&T, &[T]) {
-        match self.as_slice() {
-            [rest @ .., last] => (last, rest),
-            [] => unsafe { unreachable_unchecked() },
+    /// Flattens a slice of non-empty slices into a single vector,
+    /// placing `sep` between each of them.
+    ///
+    /// ```
+    /// # use oom::{nonempty, NonEmptySlice};
+    /// let v = [nonempty![1, 2], nonempty![3]];
+    /// let s = NonEmptySlice::from_slice(&v);
+    /// assert_eq!(s.join(&[0]), [1, 2, 0, 3]);
+    /// ```
+    pub fn join<T: Clone>(&self, sep: &[T]) -> NonEmptyVec<T>
+    where
+        V: Borrow<NonEmptySlice<T>>,
+    {
+        let (first, rest) = self.split_first();
+        let mut vec = first.borrow().as_slice().to_vec();
+        for v in rest {
+            vec.extend_from_slice(sep);
+            vec.extend_from_slice(v.borrow().as_slice());
         }
+        NonEmptyVec { inner: vec }
     }
+}
 
-    /// Returns the last and all the rest of the elements of the slice.
-    pub fn split_last_mut(&mut self) -> (&mut T, &mut [T]) {
-        match self.as_mut_slice() {
-            [rest @ .., last] => (last, rest),
-            [] => unsafe { unreachable_unchecked() },
+impl<T: Clone> NonEmptySlice<T> {
+    /// Copies `self` into a new `NonEmptyVec`.
+    pub fn to_nonempty_vec(&self) -> NonEmptyVec<T> {
+        NonEmptyVec {
+            inner: self.as_slice().to_vec(),
+        }
+    }
+
+    /// Makes a mutable reference into the given `Rc`, like `Rc::make_mut`.
+    ///
+    /// If there are other `Rc` pointers to the same slice, the slice is
+    /// cloned into a new allocation first, so they are left untouched.
+    ///
+    /// ```
+    /// # use std::rc::Rc;
+    /// # use oom::{nonempty, NonEmptySlice};
+    /// let mut a: Rc<NonEmptySlice<_>> = nonempty![1, 2].into();
+    /// let b = Rc::clone(&a);
+    /// NonEmptySlice::make_mut_rc(&mut a)[0] = 3;
+    /// assert_eq!(*a, [3, 2]);
+    /// assert_eq!(*b, [1, 2]);
+    /// ```
+    pub fn make_mut_rc(this: &mut Rc<Self>) -> &mut Self {
+        if Rc::get_mut(this).is_none() {
+            *this = Rc::from(&**this);
+        }
+        match Rc::get_mut(this) {
+            Some(slice) => slice,
+            None => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Makes a mutable reference into the given `Arc`, like `Arc::make_mut`.
+    ///
+    /// If there are other `Arc` pointers to the same slice, the slice is
+    /// cloned into a new allocation first, so they are left untouched.
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn make_mut_arc(this: &mut Arc<Self>) -> &mut Self {
+        if Arc::get_mut(this).is_none() {
+            *this = Arc::from(&**this);
+        }
+        match Arc::get_mut(this) {
+            Some(slice) => slice,
+            None => unsafe { unreachable_unchecked() },
         }
     }
 }
@@ -0,0 +1,287 @@
+use alloc::collections::{vec_deque, VecDeque};
+use core::convert::TryFrom;
+use core::fmt;
+use core::hint::unreachable_unchecked;
+use core::num::NonZeroUsize;
+use core::ops::{Index, IndexMut};
+
+use crate::{EmptyError, NonEmptySlice, NonEmptyVec};
+
+/// A non-empty double-ended queue, counterpart of `VecDeque<T>`.
+///
+/// ```
+/// # use oom::NonEmptyVecDeque;
+/// let mut q = NonEmptyVecDeque::new("idle");
+/// q.push_back("job");
+/// assert_eq!(q.pop_front(), Some("idle"));
+/// assert_eq!(q.pop_front(), None);
+/// assert_eq!(q.front(), &"job");
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyVecDeque<T> {
+    inner: VecDeque<T>,
+}
+
+const _BUILTIN_TRAITS: () = {
+    impl<T: fmt::Debug> fmt::Debug for NonEmptyVecDeque<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.inner.fmt(f)
+        }
+    }
+
+    impl<T> AsRef<VecDeque<T>> for NonEmptyVecDeque<T> {
+        fn as_ref(&self) -> &VecDeque<T> {
+            &self.inner
+        }
+    }
+
+    impl<T> Index<usize> for NonEmptyVecDeque<T> {
+        type Output = T;
+
+        fn index(&self, index: usize) -> &T {
+            &self.inner[index]
+        }
+    }
+
+    impl<T> IndexMut<usize> for NonEmptyVecDeque<T> {
+        fn index_mut(&mut self, index: usize) -> &mut T {
+            &mut self.inner[index]
+        }
+    }
+
+    impl<T> TryFrom<VecDeque<T>> for NonEmptyVecDeque<T> {
+        type Error = EmptyError;
+
+        fn try_from(deque: VecDeque<T>) -> Result<Self, Self::Error> {
+            Self::from_deque_checked(deque).map_err(|_| EmptyError)
+        }
+    }
+
+    impl<T> From<NonEmptyVecDeque<T>> for VecDeque<T> {
+        fn from(deque: NonEmptyVecDeque<T>) -> Self {
+            deque.inner
+        }
+    }
+
+    impl<T> From<NonEmptyVec<T>> for NonEmptyVecDeque<T> {
+        fn from(vec: NonEmptyVec<T>) -> Self {
+            Self {
+                inner: VecDeque::from(vec.into_vec()),
+            }
+        }
+    }
+
+    impl<T> From<NonEmptyVecDeque<T>> for NonEmptyVec<T> {
+        fn from(deque: NonEmptyVecDeque<T>) -> Self {
+            unsafe { Self::from_vec_unchecked(deque.inner.into()) }
+        }
+    }
+
+    impl<T> IntoIterator for NonEmptyVecDeque<T> {
+        type Item = T;
+        type IntoIter = vec_deque::IntoIter<T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.inner.into_iter()
+        }
+    }
+
+    impl<'a, T> IntoIterator for &'a NonEmptyVecDeque<T> {
+        type Item = &'a T;
+        type IntoIter = vec_deque::Iter<'a, T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<'a, T> IntoIterator for &'a mut NonEmptyVecDeque<T> {
+        type Item = &'a mut T;
+        type IntoIter = vec_deque::IterMut<'a, T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+
+    #[cfg(feature = "iter")]
+    impl<T> crate::FromNonEmptyIterator<T> for NonEmptyVecDeque<T> {
+        fn from_nonempty_iter<I>(iter: I) -> Self
+        where
+            I: crate::NonEmptyIterator<Item = T>,
+        {
+            Self {
+                inner: iter.into_iter().collect(),
+            }
+        }
+    }
+
+    impl<T> Extend<T> for NonEmptyVecDeque<T> {
+        fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
+
+    impl<'a, T: Copy + 'a> Extend<&'a T> for NonEmptyVecDeque<T> {
+        fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+            self.inner.extend(iter)
+        }
+    }
+};
+
+impl<T> NonEmptyVecDeque<T> {
+    /// Constructs a new `NonEmptyVecDeque` containing a single element.
+    pub fn new(first: T) -> Self {
+        let mut inner = VecDeque::new();
+        inner.push_back(first);
+        Self { inner }
+    }
+
+    /// Converts a `VecDeque<T>` into a `NonEmptyVecDeque`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if passed `VecDeque` is empty.
+    pub fn from_deque(deque: VecDeque<T>) -> Self {
+        match Self::from_deque_checked(deque) {
+            Ok(d) => d,
+            Err(_) => panic!("deque shouldn't be empty"),
+        }
+    }
+
+    /// Converts a `VecDeque<T>` into a `NonEmptyVecDeque`.
+    /// Returns passed `VecDeque` if it is empty.
+    pub fn from_deque_checked(deque: VecDeque<T>) -> Result<Self, VecDeque<T>> {
+        if deque.is_empty() {
+            return Err(deque);
+        }
+        Ok(Self { inner: deque })
+    }
+
+    /// Converts a `VecDeque<T>` into a `NonEmptyVecDeque` without
+    /// checking that it is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The passed `VecDeque` must not be empty.
+    pub unsafe fn from_deque_unchecked(deque: VecDeque<T>) -> Self {
+        debug_assert!(!deque.is_empty());
+        Self { inner: deque }
+    }
+
+    /// Returns a reference to the inner `VecDeque`.
+    pub fn as_deque(&self) -> &VecDeque<T> {
+        &self.inner
+    }
+
+    /// Converts `self` into the inner `VecDeque`.
+    pub fn into_deque(self) -> VecDeque<T> {
+        self.inner
+    }
+
+    /// Returns the number of elements in the deque.
+    pub fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.inner.len()) }
+    }
+
+    /// Always returns `false` because the deque is non-empty.
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the number of elements the deque can hold without
+    /// reallocating.
+    pub fn capacity(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.inner.capacity()) }
+    }
+
+    /// Returns the front element of the deque.
+    pub fn front(&self) -> &T {
+        match self.inner.front() {
+            Some(front) => front,
+            None => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns a mutable reference to the front element of the deque.
+    pub fn front_mut(&mut self) -> &mut T {
+        match self.inner.front_mut() {
+            Some(front) => front,
+            None => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns the back element of the deque.
+    pub fn back(&self) -> &T {
+        match self.inner.back() {
+            Some(back) => back,
+            None => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns a mutable reference to the back element of the deque.
+    pub fn back_mut(&mut self) -> &mut T {
+        match self.inner.back_mut() {
+            Some(back) => back,
+            None => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    /// Returns a reference to the element at `index`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.inner.get(index)
+    }
+
+    /// Returns a mutable reference to the element at `index`.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.inner.get_mut(index)
+    }
+
+    /// Prepends an element to the deque.
+    pub fn push_front(&mut self, value: T) {
+        self.inner.push_front(value);
+    }
+
+    /// Appends an element to the back of the deque.
+    pub fn push_back(&mut self, value: T) {
+        self.inner.push_back(value);
+    }
+
+    /// Removes the front element and returns it,
+    /// or `None` if it is the only element left.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.inner.len() == 1 {
+            return None;
+        }
+        self.inner.pop_front()
+    }
+
+    /// Removes the back element and returns it,
+    /// or `None` if it is the only element left.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.inner.len() == 1 {
+            return None;
+        }
+        self.inner.pop_back()
+    }
+
+    /// Rearranges the deque so that its elements are contiguous,
+    /// and returns them as a non-empty slice.
+    pub fn make_contiguous(&mut self) -> &mut NonEmptySlice<T> {
+        unsafe {
+            NonEmptySlice::from_mut_slice_unchecked(
+                self.inner.make_contiguous(),
+            )
+        }
+    }
+
+    /// Returns a front-to-back iterator.
+    pub fn iter(&self) -> vec_deque::Iter<'_, T> {
+        self.inner.iter()
+    }
+
+    /// Returns a front-to-back iterator that allows modifying each value.
+    pub fn iter_mut(&mut self) -> vec_deque::IterMut<'_, T> {
+        self.inner.iter_mut()
+    }
+}
@@ -0,0 +1,6 @@
+use oom::{NonEmptySlice, NonEmptyVec};
+
+fn main() {
+    let _v: NonEmptyVec<u8> = oom::nonempty![];
+    let _s: &NonEmptySlice<u8> = oom::nonempty_slice![];
+}
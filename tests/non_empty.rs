@@ -1,14 +1,16 @@
-use oom::{NonEmptyMutSlice, NonEmptyVec};
+use oom::{NonEmptySlice, NonEmptyVec};
+use std::num::NonZeroUsize;
 use std::path::Path;
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_muts() {
     let config_dirs = &mut [
         Path::new("/home/user/.config/nvim"),
         Path::new("/etc/nvim"),
         Path::new("/usr/share/nvim"),
     ];
-    let mut s = NonEmptyMutSlice::from_slice_checked(config_dirs).unwrap();
+    let s = NonEmptySlice::from_mut_slice_checked(config_dirs).unwrap();
 
     assert_eq!(s.len().get(), 3);
     assert_eq!(s.is_empty(), false);
@@ -27,7 +29,7 @@ fn test_muts() {
     }
 
     let arr = &mut [0, 1, 2];
-    let mut s = NonEmptyMutSlice::from_slice_checked(arr).unwrap();
+    let s = NonEmptySlice::from_mut_slice_checked(arr).unwrap();
 
     {
         let (first, rest) = s.split_first_mut();
@@ -55,3 +57,868 @@ fn test_muts() {
         Err(v) => assert!(v.is_empty()),
     }
 }
+
+#[test]
+fn test_vec_mutation() {
+    let mut v = NonEmptyVec::from_vec(vec![1]);
+    v.push(2);
+    v.insert(0, 0);
+    v.extend(vec![3, 4]);
+    v.extend_from_slice(&[5]);
+    let mut rest = vec![6, 7];
+    v.append(&mut rest);
+    assert!(rest.is_empty());
+    assert_eq!(v.as_slice(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+
+    assert_eq!(v.pop(), Some(7));
+    assert_eq!(v.try_remove(0), Ok(0));
+    assert_eq!(v.swap_remove(0), Ok(1));
+    assert_eq!(v.as_slice(), &[6, 2, 3, 4, 5]);
+
+    v.truncate(NonZeroUsize::new(1).unwrap());
+    assert_eq!(v.as_slice(), &[6]);
+    assert_eq!(v.pop(), None);
+    assert_eq!(v.try_remove(0), Err(oom::RemoveError::LastElement));
+    assert_eq!(
+        v.try_remove(1),
+        Err(oom::RemoveError::OutOfBounds {
+            index: 1,
+            len: NonZeroUsize::new(1).unwrap(),
+        })
+    );
+    assert_eq!(v.swap_remove(0), Err(oom::RemoveError::LastElement));
+    assert_eq!(
+        v.swap_remove(1),
+        Err(oom::RemoveError::OutOfBounds {
+            index: 1,
+            len: NonZeroUsize::new(1).unwrap(),
+        })
+    );
+    assert_eq!(v.len().get(), 1);
+}
+
+#[test]
+fn test_vec_constructors() {
+    let v = NonEmptyVec::new("a");
+    assert_eq!(v.as_slice(), &["a"]);
+
+    let v = NonEmptyVec::from_head_tail(0, Vec::new());
+    assert_eq!(v.as_slice(), &[0]);
+
+    let v = NonEmptyVec::with_capacity(0u8, NonZeroUsize::new(10).unwrap());
+    assert!(v.capacity().get() >= 10);
+    assert_eq!(v.len().get(), 1);
+
+    let v = NonEmptyVec::from_elem(
+        String::from("x"),
+        NonZeroUsize::new(1).unwrap(),
+    );
+    assert_eq!(v.as_slice(), &["x"]);
+}
+
+#[test]
+fn test_macros() {
+    let v = oom::nonempty![1, 2, 3,];
+    assert_eq!(v.as_slice(), &[1, 2, 3]);
+
+    let v = oom::nonempty![vec![0u8]; 2];
+    assert_eq!(v.as_slice(), &[vec![0], vec![0]]);
+
+    const S: &NonEmptySlice<&str> = oom::nonempty_slice!["a", "b"];
+    assert_eq!(S.len().get(), 2);
+    assert_eq!(S.last(), &"b");
+}
+
+#[test]
+fn test_macros_with_caller_consts() {
+    const N: usize = 4;
+    #[allow(non_upper_case_globals)]
+    const n: usize = 3;
+
+    assert_eq!(oom::nonempty![0u8; N], [0; 4]);
+    assert_eq!(oom::nonempty![N; 2], [4, 4]);
+    assert_eq!(oom::nonempty![1u8; n], [1; 3]);
+    assert_eq!(oom::nonempty_slice![0u8; N], &[0; 4][..]);
+    assert_eq!(oom::nonempty_slice![N; 2], &[4, 4][..]);
+
+    fn filled<const N: usize>() -> NonEmptyVec<usize> {
+        oom::nonempty![N; N]
+    }
+    assert_eq!(filled::<2>(), [2, 2]);
+}
+
+#[test]
+fn test_iterators() {
+    let s = NonEmptySlice::from_slice(&[1, 2, 3]);
+    assert_eq!(s.iter().size_hint(), (3, Some(3)));
+    assert_eq!(s.into_iter().rev().copied().collect::<Vec<_>>(), [3, 2, 1]);
+    let mut sum = 0;
+    for x in s {
+        sum += x;
+    }
+    assert_eq!(sum, 6);
+
+    let arr = &mut [1, 2, 3];
+    let s = NonEmptySlice::from_mut_slice(arr);
+    for x in &mut *s {
+        *x *= 2;
+    }
+    assert_eq!((&*s).into_iter().len(), 3);
+    for x in s {
+        *x += 1;
+    }
+    assert_eq!(arr, &[3, 5, 7]);
+
+    let mut v = oom::nonempty![1, 2, 3];
+    for x in &mut v {
+        *x *= 10;
+    }
+    assert_eq!(v.iter().next_back(), Some(&30));
+    let mut it = v.into_iter();
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next(), Some(10));
+    assert_eq!(it.next(), Some(20));
+    assert_eq!(it.next(), Some(30));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[cfg(feature = "iter")]
+#[test]
+fn test_nonempty_iterator() {
+    use oom::NonEmptyIterator;
+
+    let s = NonEmptySlice::from_slice(&[3, 1, 4, 1, 5]);
+    assert_eq!(s.nonempty_iter().first(), &3);
+    assert_eq!(s.nonempty_iter().last(), &5);
+    assert_eq!(s.nonempty_iter().count().get(), 5);
+    assert_eq!(s.nonempty_iter().min(), &1);
+    assert_eq!(s.nonempty_iter().max_by_key(|x| (**x - 3i32).abs()), &5);
+    assert_eq!(s.nonempty_iter().min_by_key(|x| (**x - 3i32).abs()), &3);
+    assert_eq!(s.nonempty_iter().rev().first(), &5);
+    assert_eq!(s.nonempty_iter().enumerate().max_by_key(|(_, x)| **x).0, 4);
+
+    let v = oom::nonempty![String::from("a"), String::from("bc")];
+    let lens: NonEmptyVec<usize> = v.nonempty_iter().map(String::len).collect();
+    assert_eq!(lens.as_slice(), &[1, 2]);
+
+    let cloned: NonEmptyVec<String> = v
+        .nonempty_iter()
+        .cloned()
+        .chain(vec![String::from("d")])
+        .collect();
+    assert_eq!(cloned.as_slice(), &["a", "bc", "d"]);
+
+    let pairs = v.into_nonempty_iter().zip(s.nonempty_iter().copied());
+    let joined = pairs
+        .map(|(s, n)| format!("{}{}", s, n))
+        .reduce(|a, b| a + &b);
+    assert_eq!(joined, "a3bc1");
+}
+
+#[cfg(feature = "array")]
+#[test]
+fn test_arrays() {
+    use oom::NonEmptyArray;
+
+    let s: &NonEmptySlice<_> = (&[1, 2, 3]).into();
+    assert_eq!(s.len().get(), 3);
+
+    let arr = &mut [1, 2, 3];
+    let s: &mut NonEmptySlice<_> = arr.into();
+    *s.last_mut() = 0;
+    assert_eq!(arr, &[1, 2, 0]);
+
+    let v = NonEmptyVec::from(["a", "b"]);
+    assert_eq!(v.as_slice(), &["a", "b"]);
+
+    let mut a = NonEmptyArray::new([1, 2, 3]);
+    *a.first_mut() = 0;
+    assert_eq!(a.len().get(), 3);
+    assert_eq!(a.split_last(), (&3, &[0, 2][..]));
+    let a = a.map(|x| x.to_string());
+    assert_eq!(a.first(), "0");
+    assert_eq!(NonEmptyVec::from(a).as_slice(), &["0", "2", "3"]);
+}
+
+#[test]
+fn test_unsized_slice() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let v = oom::nonempty![1, 2, 3];
+    assert_eq!(v.first(), &1);
+    assert!(v.as_nonempty_slice() == NonEmptySlice::from_slice(&[1, 2, 3]));
+
+    let b: Box<NonEmptySlice<i32>> = v.clone().into_boxed_slice();
+    assert_eq!(b.last(), &3);
+    let b2 = Box::<NonEmptySlice<_>>::from(&*b);
+    assert!(b == b2);
+    let v2 = NonEmptyVec::from(b);
+    assert!(v2 == v);
+
+    let rc = Rc::<NonEmptySlice<_>>::from(v.clone());
+    let rc2 = Rc::clone(&rc);
+    assert_eq!(rc2.len().get(), 3);
+    assert_eq!(Rc::<NonEmptySlice<_>>::from(&*rc).as_slice(), &[1, 2, 3]);
+
+    let arc = Arc::<NonEmptySlice<_>>::from(v);
+    let handle = std::thread::spawn({
+        let arc = Arc::clone(&arc);
+        move || *arc.last()
+    });
+    assert_eq!(handle.join().unwrap(), 3);
+    assert_eq!(NonEmptyVec::from(&*arc).as_slice(), &[1, 2, 3]);
+}
+
+#[cfg(feature = "string")]
+#[test]
+fn test_strings() {
+    use oom::{EmptyError, NonEmptyStr, NonEmptyString};
+    use std::convert::TryFrom;
+
+    let s = NonEmptyStr::from_str_checked("héllo").unwrap();
+    assert_eq!(s.len().get(), 6);
+    assert_eq!(s.first_char(), 'h');
+    assert_eq!(s.last_char(), 'o');
+    assert_eq!(s.split_last_char(), ('o', "héll"));
+    assert_eq!(s.as_bytes().len().get(), 6);
+    assert_eq!(s.to_string(), "héllo");
+
+    let mut name: NonEmptyString = "user".parse().unwrap();
+    name.push_str("name");
+    name.push('!');
+    assert_eq!(format!("{}", name), "username!");
+    assert_eq!(format!("{:?}", name), "\"username!\"");
+    let err: EmptyError = "".parse::<NonEmptyString>().unwrap_err();
+    assert_eq!(err.to_string(), "collection is empty");
+
+    let s = NonEmptyString::try_from(String::from("ab")).unwrap();
+    let mut s2 = NonEmptyString::from(&*s);
+    assert_eq!(s2.pop(), Some('b'));
+    assert_eq!(s2.pop(), None);
+    assert_eq!(s2.as_str(), "a");
+    assert_eq!(String::from(s), "ab");
+
+    let err = NonEmptyString::try_from(String::with_capacity(8)).unwrap_err();
+    assert_eq!(err.to_string(), "string is empty");
+    let _: EmptyError = err.clone().into();
+    assert_eq!(err.into_string().capacity(), 8);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    let v: NonEmptyVec<u32> = serde_json::from_str("[1, 2, 3]").unwrap();
+    assert_eq!(v.as_slice(), &[1, 2, 3]);
+    assert_eq!(serde_json::to_string(&v).unwrap(), "[1,2,3]");
+    assert_eq!(
+        serde_json::to_string(v.as_nonempty_slice()).unwrap(),
+        "[1,2,3]"
+    );
+
+    let err = match serde_json::from_str::<NonEmptyVec<u32>>("[]") {
+        Ok(_) => panic!("sequence is empty"),
+        Err(err) => err,
+    };
+    assert_eq!(
+        err.to_string(),
+        "invalid length 0, expected a non-empty sequence at line 1 column 2"
+    );
+}
+
+#[cfg(all(feature = "serde", feature = "string"))]
+#[test]
+fn test_serde_string() {
+    use oom::NonEmptyString;
+
+    let s: NonEmptyString = serde_json::from_str("\"foo\"").unwrap();
+    assert_eq!(serde_json::to_string(&s).unwrap(), "\"foo\"");
+    assert!(serde_json::from_str::<NonEmptyString>("\"\"").is_err());
+}
+
+#[test]
+fn test_try_from() {
+    use oom::{EmptyError, EmptyVecError};
+    use std::convert::TryFrom;
+
+    fn parse(items: &[u8]) -> Result<u8, EmptyError> {
+        let items = <&NonEmptySlice<u8>>::try_from(items)?;
+        Ok(*items.last())
+    }
+    assert_eq!(parse(&[1, 2]), Ok(2));
+    assert_eq!(parse(&[]).unwrap_err().to_string(), "collection is empty");
+
+    let arr = &mut [1, 2];
+    let s = <&mut NonEmptySlice<u8>>::try_from(&mut arr[..]).unwrap();
+    *s.first_mut() = 0;
+    assert_eq!(arr, &[0, 2]);
+    assert!(<&mut NonEmptySlice<u8>>::try_from(&mut [][..]).is_err());
+
+    let v = NonEmptyVec::try_from(vec![1]).unwrap();
+    assert_eq!(v.as_slice(), &[1]);
+    let err: EmptyVecError<u8> =
+        match NonEmptyVec::try_from(Vec::with_capacity(4)) {
+            Ok(_) => panic!("vec is empty"),
+            Err(err) => err,
+        };
+    assert_eq!(err.to_string(), "vec is empty");
+    assert_eq!(
+        EmptyError::from(err.clone()).to_string(),
+        "collection is empty"
+    );
+    assert_eq!(err.into_vec().capacity(), 4);
+
+    assert!(NonEmptyVec::try_from(&[1, 2][..]).is_ok());
+    assert!(NonEmptyVec::<u8>::try_from(&[][..]).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_std_error() {
+    use std::convert::TryFrom;
+    use std::error::Error;
+
+    fn parse(items: &[u8]) -> Result<u8, Box<dyn Error>> {
+        let items = <&NonEmptySlice<u8>>::try_from(items)?;
+        let v = NonEmptyVec::try_from(items.as_slice().to_vec())?;
+        Ok(*v.first())
+    }
+    assert_eq!(parse(&[3]).unwrap(), 3);
+    assert_eq!(parse(&[]).unwrap_err().to_string(), "collection is empty");
+}
+
+#[test]
+fn test_std_traits() {
+    use std::collections::{HashMap, HashSet};
+
+    let mut v = oom::nonempty![3, 1, 2];
+    assert_eq!(format!("{:?}", v), "[3, 1, 2]");
+    assert_eq!(format!("{:?}", &v[..]), "[3, 1, 2]");
+    assert_eq!(v[0], 3);
+    assert_eq!(v[1..], [1, 2]);
+    v[2] = 4;
+    AsMut::<[i32]>::as_mut(&mut v)[1] = 5;
+    assert_eq!(v, [3, 5, 4]);
+    assert_eq!(v, vec![3, 5, 4]);
+    assert_eq!(vec![3, 5, 4], v);
+    assert_eq!(v, &[3, 5, 4][..]);
+
+    let s: &NonEmptySlice<i32> = &v;
+    assert_eq!(*s, [3, 5, 4]);
+    assert_eq!(s[..2], [3, 5]);
+    assert_eq!(s, &v);
+    assert_eq!(&[3, 5, 4][..], s);
+    assert_eq!(<&[i32]>::from(s), &[3, 5, 4]);
+
+    let mut counts = HashMap::new();
+    *counts.entry(v.clone()).or_insert(0) += 1;
+    *counts.entry(v.clone()).or_insert(0) += 1;
+    assert_eq!(counts[&[3, 5, 4][..]], 2);
+
+    let set: HashSet<&NonEmptySlice<i32>> = std::iter::once(s).collect();
+    assert!(set.contains(NonEmptySlice::from_slice(&[3, 5, 4])));
+
+    let vec: Vec<i32> = v.into();
+    assert_eq!(vec, [3, 5, 4]);
+}
+
+#[test]
+fn test_chunks() {
+    let two = NonZeroUsize::new(2).unwrap();
+    let four = NonZeroUsize::new(4).unwrap();
+    let mut v = oom::nonempty![1, 2, 3, 4, 5];
+
+    let chunks: Vec<&NonEmptySlice<i32>> = v.chunks(two).into_iter().collect();
+    assert_eq!(chunks, [&[1, 2][..], &[3, 4], &[5]]);
+    let rchunks: Vec<_> =
+        v.rchunks(two).into_iter().map(|c| c.first()).collect();
+    assert_eq!(rchunks, [&4, &2, &1]);
+
+    let exact = v.chunks_exact(two);
+    assert_eq!(exact.remainder(), &[5]);
+    assert_eq!(exact.len(), 2);
+    assert_eq!(v.chunks_exact(NonZeroUsize::new(6).unwrap()).count(), 0);
+
+    let windows: Vec<_> = v.windows(four).map(|w| *w.last()).collect();
+    assert_eq!(windows, [4, 5]);
+    assert_eq!(v.windows(NonZeroUsize::new(6).unwrap()).count(), 0);
+
+    for chunk in v.chunks_mut(two) {
+        chunk.as_mut_slice().reverse();
+    }
+    assert_eq!(v, [2, 1, 4, 3, 5]);
+}
+
+#[cfg(feature = "iter")]
+#[test]
+fn test_nonempty_chunks() {
+    use oom::NonEmptyIterator;
+
+    let two = NonZeroUsize::new(2).unwrap();
+    let mut v = oom::nonempty![1, 2, 3, 4, 5];
+    assert_eq!(v.chunks(two).count().get(), 3);
+    assert_eq!(v.chunks(two).last(), &[5]);
+    assert_eq!(v.rchunks(two).map(|c| c.len().get()).max(), 2);
+    let first = v.chunks_mut(two).first();
+    *first.first_mut() = 0;
+    assert_eq!(v, [0, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_splits() {
+    let s = NonEmptySlice::from_slice(&[1, 1, 2, 3, 3]);
+    let groups: Vec<&NonEmptySlice<i32>> =
+        s.group_by(|a, b| a == b).into_iter().collect();
+    assert_eq!(groups, [&[1, 1][..], &[2], &[3, 3]]);
+
+    let pieces: Vec<_> = s.split_inclusive(|&x| x == 2).into_iter().collect();
+    assert_eq!(pieces, [&[1, 1, 2][..], &[3, 3]]);
+
+    let line = NonEmptySlice::from_slice(b"  foo bar  baz ");
+    let words: Vec<_> = line.split_nonempty(|&b| b == b' ').collect();
+    assert_eq!(words, [&b"foo"[..], b"bar", b"baz"]);
+    assert_eq!(
+        line.split_nonempty(|&b| b == b' ').next_back().unwrap(),
+        b"baz"
+    );
+    assert_eq!(
+        NonEmptySlice::from_slice(b"  ")
+            .split_nonempty(|&b| b == b' ')
+            .count(),
+        0
+    );
+
+    let mut v = oom::nonempty![1, 0, 0, 2, 3, 0];
+    for piece in v.split_nonempty_mut(|&x| x == 0) {
+        *piece.last_mut() *= 10;
+    }
+    assert_eq!(v, [10, 0, 0, 2, 30, 0]);
+    for group in v.group_by_mut(|a, b| a == b) {
+        *group.first_mut() += 1;
+    }
+    assert_eq!(v, [11, 1, 0, 3, 31, 1]);
+}
+
+#[cfg(feature = "iter")]
+#[test]
+fn test_nonempty_splits() {
+    use oom::NonEmptyIterator;
+
+    let s = NonEmptySlice::from_slice(&[1, 1, 2, 3, 3]);
+    assert_eq!(s.group_by(|a, b| a == b).count().get(), 3);
+    assert_eq!(s.group_by(|a, b| a <= b).first(), s);
+    assert_eq!(s.split_inclusive(|&x| x == 3).last(), &[3]);
+}
+
+#[test]
+fn test_sort_and_search() {
+    let mut v = oom::nonempty![5, 3, 3, 9, 1];
+    v.sort_unstable();
+    assert_eq!((*v.first(), *v.last()), (1, 9));
+    assert_eq!(v.binary_search(&9), Ok(4));
+    assert_eq!(v.binary_search(&4), Err(3));
+    assert_eq!(v.partition_point(|&x| x < 5), 3);
+
+    v.sort_by_key(|&x| std::cmp::Reverse(x));
+    assert_eq!(v, [9, 5, 3, 3, 1]);
+    v.reverse();
+    v.rotate_left(1);
+    assert_eq!(v, [3, 3, 5, 9, 1]);
+    v.rotate_right(2);
+    v.swap(0, 4);
+    assert_eq!(v, [5, 1, 3, 3, 9]);
+
+    v.dedup();
+    assert_eq!(v, [5, 1, 3, 9]);
+    v.dedup_by_key(|x| *x % 2);
+    assert_eq!(v, [5]);
+
+    let arr = &mut ["b", "c", "a"];
+    let s = NonEmptySlice::from_mut_slice(arr);
+    s.sort_by(|a, b| b.cmp(a));
+    assert_eq!(s.first(), &"c");
+}
+
+#[test]
+fn test_vec_map() {
+    let v = oom::nonempty![1u32, 2, 3];
+    let strings = v.map_ref(|x| x.to_string());
+    assert_eq!(strings, ["1", "2", "3"]);
+
+    let doubled = v.map(|x| x * 2);
+    assert_eq!(doubled, [2, 4, 6]);
+
+    let parsed = strings.clone().try_map(|s| s.parse::<i8>());
+    assert_eq!(parsed.unwrap(), [1, 2, 3]);
+    let checked = doubled.clone().try_map(|x| x.checked_sub(3).ok_or(x));
+    assert_eq!(checked, Err(2));
+
+    let mut calls = 0;
+    let shifted = doubled.clone().try_map_opt(|x| {
+        calls += 1;
+        x.checked_sub(3)
+    });
+    assert_eq!((shifted, calls), (None, 1));
+    let halved = doubled.clone().try_map_opt(|x| Some(x / 2));
+    assert_eq!(halved, Some(oom::nonempty![1, 2, 3]));
+
+    let pairs = doubled.zip(oom::nonempty!['a', 'b']);
+    assert_eq!(pairs, [(2, 'a'), (4, 'b')]);
+    let (numbers, chars) = pairs.unzip();
+    assert_eq!(numbers, [2, 4]);
+    assert_eq!(chars, ['a', 'b']);
+    assert_eq!(chars.enumerate(), [(0, 'a'), (1, 'b')]);
+}
+
+#[test]
+fn test_concat() {
+    let words = [oom::nonempty![b'a', b'b'], oom::nonempty![b'c']];
+    let words = NonEmptySlice::from_slice(&words);
+    assert_eq!(words.concat(), *b"abc");
+    assert_eq!(words.join(b", "), *b"ab, c");
+
+    let slices = [
+        NonEmptySlice::from_slice(&[1, 2]),
+        NonEmptySlice::from_ref(&3),
+    ];
+    assert_eq!(NonEmptySlice::from_slice(&slices).concat(), [1, 2, 3]);
+
+    let nested = oom::nonempty![oom::nonempty![1], oom::nonempty![2, 3]];
+    let v = nested.flatten();
+    assert_eq!(v, [1, 2, 3]);
+
+    let mut v = v + &[4][..] + vec![5] + oom::nonempty![6];
+    v += &[7][..];
+    v += Vec::new();
+    assert_eq!(v, [1, 2, 3, 4, 5, 6, 7]);
+    let v = Vec::new() + v;
+    assert_eq!(v.len().get(), 7);
+}
+
+#[test]
+fn test_split_at() {
+    fn bump(s: &mut NonEmptySlice<i32>) {
+        *s.first_mut() += 1;
+    }
+
+    let one = NonZeroUsize::new(1).unwrap();
+    let three = NonZeroUsize::new(3).unwrap();
+    let arr = &mut [1, 2, 3];
+    let s = NonEmptySlice::from_mut_slice(arr);
+    bump(&mut *s);
+    bump(s.split_at_mut(one).0);
+    let (head, tail) = s.split_at(three);
+    assert_eq!(*head, [3, 2, 3]);
+    assert!(tail.is_empty());
+
+    assert!(s.split_at_nonempty(three).is_none());
+    let (head, tail) = s.split_at_nonempty_mut(one).unwrap();
+    bump(tail);
+    assert_eq!((head.len().get(), tail.len().get()), (1, 2));
+    assert_eq!(*arr, [3, 3, 3]);
+}
+
+#[test]
+fn test_uncons() {
+    let s = NonEmptySlice::from_slice(&[1, 2]);
+    let (head, tail) = s.uncons();
+    assert_eq!(head, &1);
+    assert_eq!(tail.unwrap().uncons(), (&2, None));
+    let (last, init) = s.unsnoc();
+    assert_eq!((last, init.map(|s| s.as_slice())), (&2, Some(&[1][..])));
+
+    let mut v = oom::nonempty![1, 2, 3];
+    let mut rest = Some(&mut *v);
+    while let Some(s) = rest {
+        let (head, tail) = s.uncons_mut();
+        *head *= 10;
+        rest = tail;
+    }
+    if let (last, Some(init)) = v.unsnoc_mut() {
+        *last += *init.last();
+    }
+    assert_eq!(v, [10, 20, 50]);
+
+    assert_eq!(v.into_head_tail(), (10, vec![20, 50]));
+    assert_eq!(oom::nonempty![1].into_head_tail(), (1, vec![]));
+}
+
+#[test]
+fn test_btree() {
+    use oom::{NonEmptyBTreeMap, NonEmptyBTreeSet};
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::convert::TryFrom;
+
+    let mut routes =
+        NonEmptyBTreeMap::from(oom::nonempty![("/b", 2), ("/a", 1)]);
+    assert_eq!(routes.first_key_value(), (&"/a", &1));
+    assert_eq!(routes.last_key_value(), (&"/b", &2));
+    assert_eq!(routes["/a"], 1);
+    *routes.get_mut("/a").unwrap() = 10;
+    assert_eq!(routes.insert("/c", 3), None);
+    assert_eq!(routes.pop_last(), Some(("/c", 3)));
+    assert_eq!(routes.remove("/b"), Some(2));
+    assert_eq!(routes.remove("/a"), None);
+    assert_eq!(routes.pop_first(), None);
+    assert_eq!(routes.len().get(), 1);
+    assert_eq!(format!("{:?}", routes), r#"{"/a": 10}"#);
+    assert_eq!(NonEmptyVec::from(routes), [("/a", 10)]);
+
+    assert!(NonEmptyBTreeMap::<u8, u8>::try_from(BTreeMap::new()).is_err());
+    assert!(
+        NonEmptyBTreeMap::from_map_checked(BTreeMap::<u8, u8>::new()).is_err()
+    );
+
+    let mut perms = NonEmptyBTreeSet::new("write");
+    perms.extend(vec!["read", "exec"]);
+    assert_eq!((perms.first(), perms.last()), (&"exec", &"write"));
+    assert!(perms.remove("exec"));
+    assert_eq!(perms.pop_first(), Some("read"));
+    assert!(!perms.remove("write"));
+    assert!(perms.contains("write"));
+    assert_eq!(NonEmptyVec::from(perms.clone()), ["write"]);
+    assert_eq!(BTreeSet::from(perms).len(), 1);
+    assert!(NonEmptyBTreeSet::<u8>::try_from(BTreeSet::new()).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_hash() {
+    use oom::{NonEmptyHashMap, NonEmptyHashSet};
+    use std::collections::{HashMap, HashSet};
+    use std::convert::TryFrom;
+
+    let mut m = NonEmptyHashMap::new("a", 1);
+    m.insert("b", 2);
+    assert_eq!(m.len().get(), 2);
+    assert_eq!(m["b"], 2);
+    assert!(m.contains_key("a"));
+    assert_eq!(m.remove("a"), Some(1));
+    assert_eq!(m.remove("b"), None);
+    assert_eq!(m.clone().into_map(), HashMap::from([("b", 2)]));
+    assert_eq!(NonEmptyVec::from(m), [("b", 2)]);
+    assert!(NonEmptyHashMap::<u8, u8>::try_from(HashMap::new()).is_err());
+
+    let mut s: NonEmptyHashSet<_> = oom::nonempty![1, 2, 2].into();
+    assert_eq!(s.len().get(), 2);
+    assert!(s.remove(&1));
+    assert!(!s.remove(&2));
+    assert!(!s.insert(2));
+    assert_eq!(s, NonEmptyHashSet::new(2));
+    assert!(NonEmptyHashSet::<u8>::try_from(HashSet::new()).is_err());
+    let empty = HashSet::<u8>::with_capacity(8);
+    assert!(
+        NonEmptyHashSet::from_set_checked(empty)
+            .unwrap_err()
+            .capacity()
+            >= 8
+    );
+}
+
+#[test]
+fn test_deque_and_heap() {
+    use oom::{NonEmptyBinaryHeap, NonEmptyVecDeque};
+    use std::cmp::Reverse;
+    use std::collections::{BinaryHeap, VecDeque};
+    use std::convert::TryFrom;
+
+    let mut q = NonEmptyVecDeque::from(oom::nonempty![2, 3]);
+    q.push_front(1);
+    q.push_back(4);
+    assert_eq!((q.front(), q.back()), (&1, &4));
+    *q.front_mut() = 0;
+    q[1] = 20;
+    assert_eq!(q.pop_back(), Some(4));
+    assert_eq!(q.pop_front(), Some(0));
+    assert_eq!(q.pop_front(), Some(20));
+    assert_eq!(q.pop_back(), None);
+    assert_eq!(q.make_contiguous(), &[3][..]);
+    assert_eq!(NonEmptyVec::from(q), [3]);
+    assert!(NonEmptyVecDeque::<u8>::try_from(VecDeque::new()).is_err());
+
+    let mut tasks = NonEmptyBinaryHeap::new(Reverse(u8::MAX));
+    tasks.extend(vec![Reverse(2), Reverse(1)]);
+    assert_eq!(tasks.peek(), &Reverse(1));
+    assert_eq!(tasks.pop(), Some(Reverse(1)));
+    assert_eq!(tasks.len().get(), 2);
+    let sorted = tasks.clone().into_sorted_vec();
+    assert_eq!(sorted.first(), &Reverse(u8::MAX));
+    assert_eq!(tasks.pop(), Some(Reverse(2)));
+    assert_eq!(tasks.pop(), None);
+    assert!(
+        NonEmptyBinaryHeap::from_heap_checked(BinaryHeap::<u8>::new()).is_err()
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_rayon() {
+    use oom::NonEmptyParallelIterator;
+
+    let mut v = NonEmptyVec::from_vec((1..=1000u32).collect());
+    assert_eq!(v.par_iter().max(), &1000);
+    assert_eq!(v.par_iter().min_by_key(|&&x| x % 7), &7);
+    assert_eq!(v.par_iter().count().get(), 1000);
+    v.par_iter_mut().map(|x| *x *= 2).count();
+    assert_eq!(v.par_iter().map(|&x| x).max_by_key(|&x| x), 2000);
+    assert_eq!(
+        v.par_iter().map(|&x| x as u64).reduce_with(|a, b| a + b),
+        1_001_000
+    );
+
+    let strings: NonEmptyVec<String> =
+        v.into_par_iter().map(|x| x.to_string()).collect();
+    assert_eq!(strings.first(), "2");
+    assert_eq!(strings.len().get(), 1000);
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let mut u = Unstructured::new(&[]);
+    let v = NonEmptyVec::<u8>::arbitrary(&mut u).unwrap();
+    assert_eq!(v, [0]);
+
+    let data: Vec<u8> = (0..=255).collect();
+    let mut u = Unstructured::new(&data);
+    for _ in 0..8 {
+        let v = NonEmptyVec::<u16>::arbitrary(&mut u).unwrap();
+        assert!(v.len().get() >= 1);
+    }
+    let rest = NonEmptyVec::<u8>::arbitrary_take_rest(Unstructured::new(&[]));
+    assert_eq!(rest.unwrap(), [0]);
+}
+
+#[cfg(feature = "proptest")]
+#[test]
+fn test_proptest() {
+    use oom::proptest::nonempty_vec;
+    use proptest::prelude::*;
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    let mut runner = TestRunner::deterministic();
+    for _ in 0..32 {
+        let mut tree = nonempty_vec(any::<u8>(), 0..8)
+            .new_tree(&mut runner)
+            .unwrap();
+        assert!((1..8).contains(&tree.current().len().get()));
+        while tree.simplify() {
+            assert!(tree.current().len().get() >= 1);
+        }
+        assert_eq!(tree.current(), [0]);
+    }
+
+    let mut tree = any::<NonEmptyVec<bool>>().new_tree(&mut runner).unwrap();
+    while tree.simplify() {}
+    assert_eq!(tree.current(), [false]);
+}
+
+#[cfg(feature = "quickcheck")]
+#[test]
+fn test_quickcheck() {
+    use quickcheck::{Arbitrary, Gen, QuickCheck};
+
+    fn first_is_not_above_max(v: NonEmptyVec<u8>) -> bool {
+        v.first() <= v.iter().max().unwrap()
+    }
+    QuickCheck::new()
+        .quickcheck(first_is_not_above_max as fn(NonEmptyVec<u8>) -> bool);
+
+    let mut g = Gen::new(0);
+    assert_eq!(NonEmptyVec::<u8>::arbitrary(&mut g).len().get(), 1);
+
+    let v = NonEmptyVec::from_vec(vec![3u8, 7, 9]);
+    assert!(v.shrink().all(|s| s.len().get() >= 1));
+    assert!(NonEmptyVec::new(5u8).shrink().all(|s| s.len().get() == 1));
+}
+
+#[test]
+fn test_shared_slices() {
+    use std::convert::TryFrom;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let boxed = oom::nonempty![1, 2, 3].into_boxed_slice();
+    let cloned = boxed.clone();
+    assert_eq!(Box::<[i32]>::from(cloned), Box::from([1, 2, 3]));
+    assert!(
+        Box::<NonEmptySlice<i32>>::try_from(Box::<[i32]>::from([])).is_err()
+    );
+
+    let mut rc = Rc::<NonEmptySlice<_>>::from(boxed);
+    let shared = Rc::clone(&rc);
+    NonEmptySlice::make_mut_rc(&mut rc)[0] = 4;
+    assert!(!Rc::ptr_eq(&rc, &shared));
+    assert_eq!(*shared, [1, 2, 3]);
+    let ptr = Rc::as_ptr(&rc);
+    NonEmptySlice::make_mut_rc(&mut rc).reverse();
+    assert_eq!(Rc::as_ptr(&rc), ptr);
+    assert_eq!(NonEmptyVec::from(rc), [3, 2, 4]);
+
+    let mut arc: Arc<NonEmptySlice<_>> = NonEmptyVec::new("a").into();
+    let handle = {
+        let arc = Arc::clone(&arc);
+        std::thread::spawn(move || arc.len().get())
+    };
+    *NonEmptySlice::make_mut_arc(&mut arc).first_mut() = "b";
+    assert_eq!(handle.join().unwrap(), 1);
+    assert_eq!(NonEmptyVec::from(arc), ["b"]);
+}
+
+#[test]
+fn test_cow() {
+    use oom::NonEmptyCow;
+    use std::borrow::ToOwned;
+
+    let v = oom::nonempty![3, 1, 2];
+    let owned: NonEmptyVec<i32> = v.as_nonempty_slice().to_owned();
+    assert_eq!(owned, v.to_nonempty_vec());
+
+    let mut cow = NonEmptyCow::from(&v);
+    assert!(matches!(cow, NonEmptyCow::Borrowed(_)));
+    assert_eq!(cow.first(), &3);
+    assert_eq!(cow.len().get(), 3);
+    cow.to_mut().push(0);
+    assert!(matches!(cow, NonEmptyCow::Owned(_)));
+    assert_eq!(cow.last(), &0);
+    assert_eq!(NonEmptyVec::from(cow), [3, 1, 2, 0]);
+
+    let cow = NonEmptyCow::from(v.clone());
+    assert_eq!(cow, NonEmptyCow::from(v.as_nonempty_slice()));
+    assert_eq!(cow.into_owned(), v);
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn test_smallvec() {
+    use oom::NonEmptySmallVec;
+    use smallvec::{smallvec, SmallVec};
+    use std::convert::TryFrom;
+
+    let mut v = NonEmptySmallVec::<[u8; 4]>::from_smallvec(smallvec![3, 1]);
+    assert_eq!(v.inline_size(), 4);
+    v.extend(vec![4, 1]);
+    assert!(!v.spilled());
+    assert_eq!(v.split_first(), (&3, &[1, 4, 1][..]));
+    assert_eq!(v.last(), &1);
+    v.sort();
+    assert_eq!(v, [1, 1, 3, 4]);
+    let s: &NonEmptySlice<u8> = &v;
+    assert_eq!(s.len().get(), 4);
+
+    v.push(5);
+    assert!(v.spilled());
+    v.truncate(NonZeroUsize::new(2).unwrap());
+    v.shrink_to_fit();
+    assert!(!v.spilled());
+    assert_eq!(v.pop(), Some(1));
+    assert_eq!(v.pop(), None);
+
+    let vec = NonEmptyVec::from(v.clone());
+    assert_eq!(NonEmptySmallVec::<[u8; 4]>::from(vec), v);
+    assert!(NonEmptySmallVec::try_from(SmallVec::<[u8; 1]>::new()).is_err());
+}